@@ -0,0 +1,22 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use learning_rust::{convert_batch, Unit};
+
+// a million readings spread from well below freezing to above boiling
+fn readings() -> Vec<f64> {
+    (0..1_000_000).map(|i| -100.0 + (i % 3_000) as f64 * 0.1).collect()
+}
+
+// the only allocation should be the output Vec, so this ought to stay well under a millisecond
+fn batch_conversion(c: &mut Criterion) {
+    let values = readings();
+
+    c.bench_function("convert_batch 1M F to C", |b| {
+        b.iter(|| convert_batch(black_box(&values), Unit::Fahrenheit, Unit::Celsius))
+    });
+    c.bench_function("convert_batch 1M C to R", |b| {
+        b.iter(|| convert_batch(black_box(&values), Unit::Celsius, Unit::Rankine))
+    });
+}
+
+criterion_group!(benches, batch_conversion);
+criterion_main!(benches);
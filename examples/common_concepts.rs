@@ -0,0 +1,256 @@
+// notes from chapter 3 of the book, "Common Programming Concepts"
+// run with `cargo run --example common_concepts`
+
+// constants are never mutable and must use an all uppercase snake case naming convention
+const THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;
+
+fn main() {
+    variables();
+    shadowing();
+    scalar_types();
+    compound_types();
+    functions();
+    control_flow();
+}
+
+fn variables() {
+    // variables are immutable by default
+    let x = 5;
+    println!("x = {x}");
+
+    // to let variables be mutable, use mut
+    let mut x = 6;
+    println!("x = {x}");
+    x += 1;
+    println!("x = {x}");
+
+    println!("three hours is {THREE_HOURS_IN_SECONDS} seconds");
+}
+
+fn shadowing() {
+    // shadowing is when you create a second variable with the same name but give it a different value
+    // in this case the second variable will "overshadow" the original until it itself is shadowed
+    // or ultimately when it's scope ends
+    //
+    // shadowing is different than mut because instead of reassigning the value of a variable,
+    // it is instead creating a second variable with the same name of a different value using the "let"
+    // keyword
+    //
+    // with shadowing, we can change the datatype of the variable which can't be done with mut
+    let x = 5;
+    let x = x + 1;
+    {
+        let x = x * 2;
+        println!("the value of x in the inner scope is: {x}");
+    }
+    println!("the value of x is: {x}");
+}
+
+fn scalar_types() {
+    // rust is statically typed but with built in inference capabilities
+    // where inference can not be used, you must annotate the datatype
+    let guess: u32 = "42".parse().expect("Not a number!");
+    println!("guess = {guess}");
+
+    // scalar types represent a single value and have four primary types:
+    // integers, floating point numbers, booleans, characters
+
+    // integers are a number without a fractional component and can be signed (include negatives: i)
+    // or unsigned (only positive numbers: u)
+    //
+    // the data length of the integer has 6 options (default is i32):
+    // 8bit, 16bit, 32bit, 64bit, 128bit, and arch (dependent on pc architecture i.e. 32bit/64bit)
+    //
+    // integer overflow will occur if value is outside datatype bounds, causing wrapping to the value
+    let num: i8 = 42; // signed 8 bit
+    println!("i8: {num}");
+    let num: u128 = 42; // unsigned 128 bit
+    println!("u128: {num}");
+
+    // integer literals can be written in 5 ways:
+    // decimal, hex, octal, binary, byte(u8 only)
+    let dec: i32 = 98_222;
+    let hex: i32 = 0xff;
+    let oct: i32 = 0o77;
+    let bin: i32 = 0b1111_0000;
+    let byt: u8 = b'A';
+    println!("dec {dec}, hex {hex}, oct {oct}, bin {bin}, byte {byt}");
+
+    // floating points are numbers with decimal points, and all fp numbers are signed in rust
+    // there are two types in rust, f32 and f64 (f64 is the default type)
+    let x = 2.0; // f64
+    let y: f32 = 3.0; // f32
+    println!("x = {x}, y = {y}");
+
+    // rust has all the basic mathematical operators built in
+    // addition
+    let sum = 5 + 10;
+    // subtraction
+    let difference = 95.5 - 4.3;
+    // multiplication
+    let product = 4 * 30;
+    // division -- integer division rounds down to the nearest integer
+    let quotient = 56.7 / 32.2;
+    let floored = 2 / 3; // Results in 0
+    // remainder
+    let remainder = 43 % 5;
+    println!("{sum} {difference} {product} {quotient} {floored} {remainder}");
+
+    // rust's boolean values can be implemented as seen below:
+    let t = true;
+    let f: bool = false; // with explicit type annotation
+    println!("t = {t}, f = {f}");
+
+    // char values are the most primitive alphabetic type and use single quotes (whereas string literals
+    // use double quotes)
+    // chars are represented by Unicode Scalar Value and can therefore use more than just ASCII
+    // i.e. accented letters, asian language characters, emojis, and zero width characters
+    let c = 'z';
+    let z: char = 'ℤ'; // with explicit type annotation
+    let rustaceans = '🦀';
+    println!("{c} {z} {rustaceans}");
+}
+
+fn compound_types() {
+    // tuples group together a number of values with a variety of types into one compound type
+    // to define:
+    let tup: (i32, f64, u8) = (500, 6.4, 1);
+    // to easily destructure:
+    let (x, y, z) = tup;
+    println!("x = {x}, y = {y}, z = {z}");
+    // to directly access an element:
+    let five_hundred = tup.0;
+    let six_point_four = tup.1;
+    let one = tup.2;
+    println!("{five_hundred} {six_point_four} {one}");
+
+    // in rust, arrays are like tuples, however all elements must have the same type and a fixed length
+    // arrays have their data allocated on the stack instead of the heap
+    // rust will panic at runtime if you try to access an out of bounds element of an array
+    // to define:
+    let a: [i32; 5] = [1, 2, 3, 4, 5];
+    let b = [3; 5]; //creates an array where all elements have the value 3
+    // accessing elements
+    let first = a[0];
+    let second = a[1];
+    println!("{a:?} {b:?} first = {first}, second = {second}");
+}
+
+// rust starts and runs in the main() function
+// function names should be lower snake case and can be defined like the following:
+fn functions() {
+    println!("Hello, world!");
+    another_function(); // calling a second function
+
+    // parameters can be passed to functions, however all parameters must have a defined type and be
+    // defined in the function declaration
+    // multiple parameters can be defined using commas
+    print_labeled_measurement(5, 'h');
+
+    // statements are instructions that perform some action and do not return a value
+    let y = 6;
+    println!("y = {y}");
+
+    // Expressions evaluate to a resulting value
+    // expressions do not have a semicolon at the end of the line, if it does, it is actually a statement
+    let y = {
+        let x = 3;
+        x + 1
+    };
+    println!("y = {y}");
+
+    println!("five() returns {}", five());
+}
+
+fn another_function() {
+    println!("Another function.");
+}
+
+fn print_labeled_measurement(value: i32, unit_label: char) {
+    println!("The measurement is: {value}{unit_label}");
+}
+
+// return values allow function to send data back to the code that calls them
+// return values are not named, but must be declared with ->
+// statements cannot be returned, but expressions can
+fn five() -> i32 {
+    5
+}
+
+fn control_flow() {
+    // if expressions allow you to run snippets of code based on certain conditions
+    // the condition logic must always result in a boolean value
+    // only the first block with logic that evaluates to true will be run
+    // elif can be used for multiple conditions
+    // else can be used as an endcap for coverage if none of the other conditions evaluate to true
+    let number = 6;
+
+    if number % 4 == 0 {
+        println!("number is divisible by 4");
+    } else if number % 3 == 0 {
+        println!("number is divisible by 3");
+    } else if number % 2 == 0 {
+        println!("number is divisible by 2");
+    } else {
+        println!("number is not divisible by 4, 3, or 2");
+    }
+
+    // since if is an expressions, it can be used to assign values in a let statement
+    let condition = true;
+    let number = if condition { 5 } else { 6 };
+
+    println!("The value of number is: {number}");
+
+    // rust has 3 types of loops: loop, while, for
+
+    // loop will iterate over a section of code until you tell it to stop with break
+    // continue will skip over the rest of the code for that loop and restart at the top of the loop
+    // to return a value from a loop, just add the value you want returned after the break expression
+    let mut counter = 0;
+    let result = loop {
+        counter += 1;
+
+        if counter == 10 {
+            break counter * 2;
+        }
+    };
+    println!("The result is {result}");
+
+    // if you have multiple loops together, you can add a label to a loop as well as its control code
+    let mut count = 0;
+    'counting_up: loop {
+        println!("count = {count}");
+        let mut remaining = 10;
+        loop {
+            println!("remaining = {remaining}");
+            if remaining == 9 {
+                break;
+            }
+            if count == 2 {
+                break 'counting_up;
+            }
+            remaining -= 1;
+        }
+
+        count += 1;
+    }
+    println!("End count = {count}");
+
+    // a while loop will continue to loop as long as a condition is met
+    let mut number = 3;
+
+    while number != 0 {
+        println!("{number}!");
+
+        number -= 1;
+    }
+
+    println!("LIFTOFF!!!");
+
+    // for iterative loops where conditional logic isn't needed, for loops should be used
+    let a = [10, 20, 30, 40, 50];
+
+    for element in a {
+        println!("the value is: {element}");
+    }
+}
@@ -0,0 +1,419 @@
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::ch3_practice::{fibonacci, run_converter, twelve_days, FibError};
+use crate::cli::{self, ArgError, ConvertRequest, ParsedArgs};
+use crate::config::{load_config, ColorMode, Config, CONFIG_FILE};
+use crate::error::{error_exit_code, TempError};
+use crate::file::{
+    convert_file_to_csv, convert_file_with_progress, convert_lines_table, convert_lines_with,
+    summary_line,
+};
+use crate::guess::guessing_game;
+use crate::input::is_interactive;
+use crate::mass::{convert_mass, mass_line, MassUnit};
+use crate::ownership::ownership_demo;
+use crate::session::{log_conversion, ConvertRecord};
+use crate::table::print_all;
+use crate::temperature::{
+    check_absolute_zero, check_range, format_result, max_roundtrip_error, parse_temperature,
+    Unit, DEFAULT_PRECISION,
+};
+
+pub const USAGE: &str = "\
+usage:
+  learning_rust --help | --version                     print this help or the version
+  learning_rust convert [VALUE --from UNIT --to UNIT]  convert a temperature, interactive with no value
+  ... | learning_rust convert                         convert each piped line from F to C
+  learning_rust convert --table                        convert each line until input ends, then
+                                                       print them all as a table
+  learning_rust file PATH FROM TO [--summary|--csv]    convert every temperature in a file,
+                                                       --strict and --max-kelvin work here too
+  learning_rust fib N                                  print the nth fibonacci number
+  learning_rust guess                                  play the guess the number game
+  learning_rust mass VALUE FROM TO                     convert a mass, i.e. `mass 1 lb g`
+  learning_rust ownership                              walk through the ownership rules
+  learning_rust showall VALUE UNIT                     show a temperature in every unit
+  learning_rust song                                   print the twelve days of christmas
+  learning_rust selfcheck                              check the conversions round-trip accurately
+
+convert flags:
+  --decimals N                round the result to N decimals, 0 to 15, defaults to 2
+  --round nearest|floor|ceil  how the result is rounded, defaults to nearest
+  -q, --quiet                 print just the number, for piping into other tools
+  --format text|json          print the whole conversion as a JSON object with json
+  --explain                   also print the formula that was used
+  --no-color                  turn off the colored result
+  --log PATH                  append every conversion to the file at PATH
+  --strict                    reject values hotter than the surface of the sun as likely typos
+  --max-kelvin K              like --strict, with a different maximum given in kelvin
+  --precision f32|f64         the float type the math is done in, defaults to f64
+  --round-trip-check          warn when rounding loses too much to convert back";
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// the most a value may drift after a round trip before selfcheck fails
+pub const SELF_CHECK_EPSILON: f64 = 1e-9;
+
+// the exercise picked by the first argument, along with whatever it needs
+#[derive(Debug, PartialEq)]
+pub enum Command<'a> {
+    Convert(&'a [String]),
+    File(&'a Path, Unit, Unit, FileOutput, Option<f64>),
+    Fib(u32),
+    Guess,
+    Mass(f64, MassUnit, MassUnit),
+    Ownership,
+    ShowAll(f64, Unit),
+    Song,
+    SelfCheck,
+}
+
+// how `file` prints what it converted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FileOutput {
+    // one result per line
+    Lines,
+    // one result per line, then a `---` line and the summary_line (--summary)
+    Summary,
+    // `input,result` rows under a header, see convert_file_to_csv (--csv)
+    Csv,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AppError {
+    UnknownCommand(String),
+    InvalidArgument(String),
+    Arg(ArgError),
+    Temp(TempError),
+    Fib(FibError),
+    SelfCheckFailed(f64),
+    // piped lines that were reported and skipped, holding the first one's error
+    SkippedLines(TempError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::UnknownCommand(command) => write!(f, "unknown command '{command}'"),
+            AppError::InvalidArgument(reason) => write!(f, "{reason}"),
+            AppError::Arg(err) => write!(f, "{err}"),
+            AppError::Temp(err) => write!(f, "{err}"),
+            AppError::Fib(err) => write!(f, "{err}"),
+            AppError::SkippedLines(_) => write!(f, "some lines could not be converted, see above"),
+            AppError::SelfCheckFailed(error) => {
+                write!(f, "round-trip error {error:e} is above the allowed {SELF_CHECK_EPSILON:e}")
+            }
+        }
+    }
+}
+
+impl AppError {
+    // what the process should exit with, bad arguments count as parse errors
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            AppError::Temp(err) | AppError::SkippedLines(err) => error_exit_code(err),
+            AppError::UnknownCommand(_) | AppError::InvalidArgument(_) | AppError::Arg(_) => 2,
+            AppError::Fib(_) => 3,
+            AppError::SelfCheckFailed(_) => 1,
+        }
+    }
+}
+
+impl From<ArgError> for AppError {
+    fn from(err: ArgError) -> Self {
+        AppError::Arg(err)
+    }
+}
+
+impl From<TempError> for AppError {
+    fn from(err: TempError) -> Self {
+        AppError::Temp(err)
+    }
+}
+
+impl From<FibError> for AppError {
+    fn from(err: FibError) -> Self {
+        AppError::Fib(err)
+    }
+}
+
+// works out which exercise to run without running it
+// with no arguments at all the converter is picked, same as before subcommands existed
+pub fn route(args: &[String]) -> Result<Command<'_>, AppError> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(Command::Convert(args));
+    };
+
+    match command.as_str() {
+        "convert" => Ok(Command::Convert(rest)),
+        // the converter's parse_args handles these, so they work with or without `convert`
+        "--help" | "-h" | "--version" | "-V" => Ok(Command::Convert(args)),
+        "file" => {
+            // --summary, --csv, --strict, and --max-kelvin can go anywhere after `file`,
+            // everything else is positional
+            let (max_kelvin, rest) = cli::split_strict_flags(rest)?;
+            let summary = rest.iter().any(|arg| *arg == "--summary");
+            let csv = rest.iter().any(|arg| *arg == "--csv");
+            let output = match (summary, csv) {
+                (false, false) => FileOutput::Lines,
+                (true, false) => FileOutput::Summary,
+                (false, true) => FileOutput::Csv,
+                (true, true) => {
+                    return Err(AppError::InvalidArgument(String::from(
+                        "--summary and --csv can't be used together",
+                    )));
+                }
+            };
+            let rest: Vec<&String> =
+                rest.into_iter().filter(|arg| *arg != "--summary" && *arg != "--csv").collect();
+            let [path, from, to] = rest[..] else {
+                return Err(AppError::InvalidArgument(String::from(
+                    "file needs a path and two units, i.e. `file temps.txt f c`",
+                )));
+            };
+            Ok(Command::File(Path::new(path), from.parse()?, to.parse()?, output, max_kelvin))
+        }
+        "fib" => {
+            let n = rest.first().ok_or_else(|| {
+                AppError::InvalidArgument(String::from("fib needs a number, i.e. `fib 10`"))
+            })?;
+            let n = n.parse().map_err(|_| {
+                AppError::InvalidArgument(format!("'{n}' is not a valid fibonacci index"))
+            })?;
+            Ok(Command::Fib(n))
+        }
+        "guess" => Ok(Command::Guess),
+        "mass" => {
+            let [value, from, to] = rest else {
+                return Err(AppError::InvalidArgument(String::from(
+                    "mass needs a value and two units, i.e. `mass 1 lb g`",
+                )));
+            };
+            let value = value.parse().map_err(|_| {
+                AppError::InvalidArgument(format!("'{value}' is not a valid mass"))
+            })?;
+            Ok(Command::Mass(value, from.parse()?, to.parse()?))
+        }
+        "ownership" => Ok(Command::Ownership),
+        "showall" => {
+            let [value, unit] = rest else {
+                return Err(AppError::InvalidArgument(String::from(
+                    "showall needs a value and its unit, i.e. `showall 98.6 f`",
+                )));
+            };
+            let value = parse_temperature(value)?;
+            let unit = unit.parse()?;
+            check_absolute_zero(value, unit)?;
+            Ok(Command::ShowAll(value, unit))
+        }
+        "song" => Ok(Command::Song),
+        "selfcheck" => Ok(Command::SelfCheck),
+        _ => Err(AppError::UnknownCommand(command.to_string())),
+    }
+}
+
+// runs the exercise picked by the first argument, without the program name
+pub fn dispatch(args: &[String]) -> Result<(), AppError> {
+    dispatch_with(args, &AtomicBool::new(false))
+}
+
+// dispatch, but a long `file` conversion stops early once cancel is set
+pub fn dispatch_with(args: &[String], cancel: &AtomicBool) -> Result<(), AppError> {
+    match route(args)? {
+        Command::Convert(args) => run_convert_command(args),
+        Command::File(path, from, to, output, max_kelvin) => {
+            let config = load_local_config()?;
+            let max_kelvin = max_kelvin.or(config.as_ref().and_then(|config| config.max_kelvin));
+            let precision = config.map_or(DEFAULT_PRECISION, |config| config.precision);
+            run_file_command(path, from, to, output, precision, max_kelvin, cancel)
+        }
+        Command::Fib(n) => {
+            println!("{}", fibonacci(n)?);
+            Ok(())
+        }
+        Command::Guess => Ok(guessing_game()?),
+        Command::Mass(value, from, to) => {
+            let result = convert_mass(value, from, to)?;
+            println!("{}", mass_line(value, from, to, result));
+            Ok(())
+        }
+        Command::Ownership => {
+            ownership_demo();
+            Ok(())
+        }
+        Command::ShowAll(value, unit) => {
+            print!("{}", print_all(value, unit));
+            Ok(())
+        }
+        Command::Song => {
+            print!("{}", twelve_days());
+            Ok(())
+        }
+        Command::SelfCheck => self_check(),
+    }
+}
+
+// round-trips a spread of values from absolute zero up to 1000 and reports the worst error
+fn self_check() -> Result<(), AppError> {
+    let samples: Vec<f64> = (-459..=1000).map(|degree| degree as f64 + 0.33).collect();
+    let error = max_roundtrip_error(&samples);
+
+    println!("checked {} values, max round-trip error: {error:e}", samples.len());
+    if error > SELF_CHECK_EPSILON {
+        return Err(AppError::SelfCheckFailed(error));
+    }
+
+    Ok(())
+}
+
+// prints the results with `precision` decimals the way `output` asks for, progress goes to
+// stderr so it stays out of the results
+fn run_file_command(
+    path: &Path,
+    from: Unit,
+    to: Unit,
+    output: FileOutput,
+    precision: u32,
+    max_kelvin: Option<f64>,
+    cancel: &AtomicBool,
+) -> Result<(), AppError> {
+    if output == FileOutput::Csv {
+        print!("{}", convert_file_to_csv(path, from, to, precision, max_kelvin)?);
+        return Ok(());
+    }
+
+    let results = convert_file_with_progress(path, from, to, max_kelvin, io::stderr(), cancel)?;
+
+    let mut stdout = io::stdout().lock();
+    for result in &results {
+        writeln!(stdout, "{}", format_result(*result, precision)).map_err(TempError::from)?;
+    }
+    let summary = output == FileOutput::Summary;
+    if let Some(line) = summary.then(|| summary_line(&results)).flatten() {
+        writeln!(stdout, "---\n{line}").map_err(TempError::from)?;
+    }
+    stdout.flush().map_err(TempError::from)?;
+
+    if cancel.load(Ordering::Relaxed) {
+        eprintln!("cancelled after {} values", results.len());
+    }
+
+    Ok(())
+}
+
+// a .tempconfig in the current directory fills in whatever flags are left off
+fn load_local_config() -> Result<Option<Config>, AppError> {
+    let config_path = Path::new(CONFIG_FILE);
+    if config_path.exists() {
+        Ok(Some(load_config(config_path)?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn run_convert_command(args: &[String]) -> Result<(), AppError> {
+    // --help and --version are answered before the config is read, so a broken .tempconfig
+    // can't stop them from working
+    match cli::parse_args(args) {
+        Ok(ParsedArgs::Help) => {
+            println!("{USAGE}");
+            return Ok(());
+        }
+        Ok(ParsedArgs::Version) => {
+            println!("learning_rust {VERSION}");
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let config = load_local_config()?;
+    let color = config.as_ref().map_or(ColorMode::Auto, |config| config.color);
+    let (from, to) = config.as_ref().map_or((Unit::Fahrenheit, Unit::Celsius), |c| (c.from, c.to));
+
+    // --strict and --max-kelvin work without a value too, for the table, piped, and
+    // interactive modes below, a flag wins over the config's max_kelvin
+    let (max_kelvin, rest) = cli::split_strict_flags(args)?;
+    let max_kelvin = max_kelvin.or(config.as_ref().and_then(|config| config.max_kelvin));
+
+    // the table can only be printed once input ends, so values come from stdin like piped input
+    if rest.iter().any(|arg| *arg == "--table") {
+        if rest.len() > 1 {
+            return Err(AppError::InvalidArgument(String::from(
+                "--table reads values from stdin, so it only goes with --strict or --max-kelvin",
+            )));
+        }
+        let stdin = io::stdin();
+        return convert_lines_table(stdin.lock(), io::stdout(), io::stderr(), from, to, max_kelvin)
+            .map_err(skipped_lines);
+    }
+
+    if !rest.is_empty() {
+        return match cli::parse_args_with(args, config.as_ref())? {
+            ParsedArgs::Convert(req) => run_request(&req, color),
+            // already printed above
+            ParsedArgs::Help | ParsedArgs::Version => Ok(()),
+        };
+    }
+
+    // when stdin is piped (i.e. in CI) the request can come from the environment,
+    // otherwise every piped line is converted from fahrenheit to celsius
+    if !is_interactive() {
+        if let Some(req) = cli::from_env() {
+            return run_request(&ConvertRequest { max_kelvin, ..req? }, color);
+        }
+
+        let stdin = io::stdin();
+        return convert_lines_with(stdin.lock(), io::stdout(), io::stderr(), from, to, max_kelvin)
+            .map_err(skipped_lines);
+    }
+
+    run_converter(max_kelvin);
+    Ok(())
+}
+
+// bad lines were already printed as they came up, so they're only summed up at the end,
+// anything else (i.e. stdout closing) is passed on as is
+fn skipped_lines(err: TempError) -> AppError {
+    match err {
+        TempError::Line(..) => AppError::SkippedLines(err),
+        err => AppError::Temp(err),
+    }
+}
+
+fn run_request(req: &ConvertRequest, color: ColorMode) -> Result<(), AppError> {
+    // unless the config says always, escape codes would just be noise when the output is
+    // piped somewhere
+    let req = &ConvertRequest {
+        color: req.color && color.use_color(io::stdout().is_terminal()),
+        ..req.clone()
+    };
+
+    check_range(req.value, req.from, req.max_kelvin)?;
+    let result = req.convert();
+
+    println!("{}", cli::format_output(req, result));
+    if let Some(warning) = req.roundtrip_warning() {
+        eprintln!("{warning}");
+    }
+    if req.explain {
+        println!("{}", cli::explain(req, result));
+    }
+
+    // the conversion already happened, so a log that can't be written is only worth a warning
+    if let Some(path) = &req.log {
+        let record = ConvertRecord {
+            input: req.value,
+            from: req.from,
+            to: req.to,
+            result: req.round(result),
+            precision: req.precision,
+        };
+        if let Err(err) = log_conversion(path, &record) {
+            eprintln!("warning: could not write to log {}: {err}", path.display());
+        }
+    }
+
+    Ok(())
+}
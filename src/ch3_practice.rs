@@ -1,20 +1,476 @@
-use std::io;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use crate::commands::{CommandRegistry, CommandResult};
+use crate::error::TempError;
+use crate::input::{read_input, read_input_into};
+use crate::session::{ConvertRecord, Session};
+use crate::temperature::{
+    check_range, convert, describe, parse_temperature, round_to, Unit, DEFAULT_PRECISION,
+    MAX_PRECISION,
+};
 
-pub fn temp_convert(){
-    println!("Input a temp to convert to Celsius");
+// the conversions the user can pick from in run_converter
+#[derive(Debug, PartialEq)]
+pub enum Direction {
+    FahrenheitToCelsius,
+    CelsiusToFahrenheit,
+    FahrenheitToKelvin,
+    CelsiusToKelvin,
+}
+
+// accepts either the menu number or the written direction, i.e. "1", "f to c", or
+// "Celsius to Kelvin"
+pub fn parse_direction(choice: &str) -> Option<Direction> {
+    let choice = choice.trim().to_lowercase();
+
+    match choice.as_str() {
+        "1" => return Some(Direction::FahrenheitToCelsius),
+        "2" => return Some(Direction::CelsiusToFahrenheit),
+        "3" => return Some(Direction::FahrenheitToKelvin),
+        "4" => return Some(Direction::CelsiusToKelvin),
+        _ => {}
+    }
+
+    let (from, to) = choice.split_once(" to ")?;
+    let from: Unit = from.parse().ok()?;
+    let to: Unit = to.parse().ok()?;
+
+    match (from, to) {
+        (Unit::Fahrenheit, Unit::Celsius) => Some(Direction::FahrenheitToCelsius),
+        (Unit::Celsius, Unit::Fahrenheit) => Some(Direction::CelsiusToFahrenheit),
+        (Unit::Fahrenheit, Unit::Kelvin) => Some(Direction::FahrenheitToKelvin),
+        (Unit::Celsius, Unit::Kelvin) => Some(Direction::CelsiusToKelvin),
+        _ => None,
+    }
+}
+
+impl Direction {
+    // the (from, to) units for this direction
+    pub fn units(&self) -> (Unit, Unit) {
+        match self {
+            Direction::FahrenheitToCelsius => (Unit::Fahrenheit, Unit::Celsius),
+            Direction::CelsiusToFahrenheit => (Unit::Celsius, Unit::Fahrenheit),
+            Direction::FahrenheitToKelvin => (Unit::Fahrenheit, Unit::Kelvin),
+            Direction::CelsiusToKelvin => (Unit::Celsius, Unit::Kelvin),
+        }
+    }
+}
+
+// a blank answer keeps the default precision, more than MAX_PRECISION places is None
+pub fn parse_precision(input: &str) -> Option<u32> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        Some(DEFAULT_PRECISION)
+    } else {
+        input.parse().ok().filter(|&precision| precision <= MAX_PRECISION)
+    }
+}
+
+// what the converter loop should do with a line of input
+#[derive(Debug, PartialEq)]
+pub enum LoopAction {
+    Convert(f64),
+    ConvertMany(Vec<String>),
+    Empty,
+    History,
+    Last,
+    Repeat,
+}
+
+// a blank or whitespace-only line is Empty, meaning nothing has been typed yet
+// help and quit aren't here, they're registered commands (see commands.rs)
+// a line with commas is a list like "32, 212, 98.6", each piece is converted on its own
+// so one bad value doesn't stop the rest, empty pieces from i.e. "32,,212" are skipped
+pub fn next_action(input: &str) -> Result<LoopAction, TempError> {
+    if input.contains(',') {
+        let values = input
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(String::from)
+            .collect();
+        return Ok(LoopAction::ConvertMany(values));
+    }
+
+    match input.trim().to_lowercase().as_str() {
+        "" => Ok(LoopAction::Empty),
+        "history" => Ok(LoopAction::History),
+        "last" => Ok(LoopAction::Last),
+        "repeat" => Ok(LoopAction::Repeat),
+        _ => Ok(LoopAction::Convert(parse_temperature(input)?)),
+    }
+}
+
+// everything convert_loop understands, printed when the user types `help` or `?`
+pub fn help_text() -> &'static str {
+    "\
+commands:
+  a number      convert it, i.e. 98.6, -40, or 98 3/5
+  a list        convert each number in a comma separated list, i.e. 32, 212, 98.6
+  history       list every conversion so far
+  last          show the most recent conversion
+  repeat        convert another value between the same units as the last one
+  help, ?       show this list
+  q, quit       stop converting"
+}
+
+// keeps converting temperatures between two units until the user quits
+// every conversion is recorded in the session so `history`, `last`, and `repeat` can use them
+pub fn convert_loop<R: BufRead, W: Write>(
+    input: R,
+    output: W,
+    session: &mut Session,
+    from: Unit,
+    to: Unit,
+    precision: u32,
+) -> io::Result<()> {
+    let commands = CommandRegistry::default();
+    convert_loop_with_commands(input, output, session, &commands, from, to, precision)
+}
+
+// convert_loop with its own set of commands, each line is checked against them first
+// and only treated as a temperature or a built in action when none of them match
+pub fn convert_loop_with_commands<R: BufRead, W: Write>(
+    mut input: R,
+    mut output: W,
+    session: &mut Session,
+    commands: &CommandRegistry,
+    from: Unit,
+    to: Unit,
+    precision: u32,
+) -> io::Result<()> {
+    let prompt = format!("Input a temp to convert to {to:?} (help for commands, q to quit)");
+    let mut line = String::new();
+
+    while read_input_into(&mut input, &mut output, &prompt, &mut line)? {
+        match commands.dispatch(&line) {
+            Some(CommandResult::Output(text)) => {
+                writeln!(output, "{text}")?;
+                continue;
+            }
+            Some(CommandResult::Failed(reason)) => {
+                writeln!(output, "{reason}, try again")?;
+                continue;
+            }
+            Some(CommandResult::Quit) => break,
+            None => {}
+        }
+
+        match next_action(&line) {
+            Ok(LoopAction::Convert(value)) => {
+                convert_and_record(&mut output, session, value, from, to, precision)?;
+            }
+            Ok(LoopAction::ConvertMany(values)) => {
+                for value in values {
+                    match parse_temperature(&value) {
+                        Ok(temp) => {
+                            convert_and_record(&mut output, session, temp, from, to, precision)?
+                        }
+                        Err(err) => writeln!(output, "skipping '{value}': {err}")?,
+                    }
+                }
+            }
+            // just prompt again, a stray enter isn't worth an error
+            Ok(LoopAction::Empty) => continue,
+            Ok(LoopAction::History) => print_history(&mut output, session)?,
+            Ok(LoopAction::Last) => match session.history().last() {
+                Some(record) => writeln!(output, "{record}")?,
+                None => writeln!(output, "No conversions yet")?,
+            },
+            Ok(LoopAction::Repeat) => {
+                let Some(last) = session.history().last() else {
+                    writeln!(output, "No conversions yet, nothing to repeat")?;
+                    continue;
+                };
+                let (from, to) = (last.from, last.to);
 
-    let mut degf = String::new();
+                let prompt = format!("Input a temp to convert from {from:?} to {to:?}");
+                if !read_input_into(&mut input, &mut output, &prompt, &mut line)? {
+                    break;
+                }
 
-    io::stdin()
-        .read_line(&mut degf)
+                match parse_temperature(&line) {
+                    Ok(value) => convert_and_record(&mut output, session, value, from, to, precision)?,
+                    Err(err) => writeln!(output, "{err}, try again")?,
+                }
+            }
+            Err(err) => writeln!(output, "{err}, try again")?,
+        }
+    }
+
+    Ok(())
+}
+
+// converts a single value, printing the result and adding it to the session history
+fn convert_and_record<W: Write>(
+    output: &mut W,
+    session: &mut Session,
+    value: f64,
+    from: Unit,
+    to: Unit,
+    precision: u32,
+) -> io::Result<()> {
+    match check_range(value, from, session.max_kelvin()).map(|value| convert(value, from, to)) {
+        Ok(result) => {
+            let result = round_to(result, precision);
+            let temp = from.temperature(value);
+            let feel = describe(temp.to_celsius());
+            let record = ConvertRecord { input: value, from, to, result, precision };
+            writeln!(output, "{record} ({feel})")?;
+            session.record(record);
+        }
+        Err(err) => writeln!(output, "{err}, try again")?,
+    }
+
+    Ok(())
+}
+
+fn print_history<W: Write>(output: &mut W, session: &Session) -> io::Result<()> {
+    if session.history().is_empty() {
+        writeln!(output, "No conversions yet")?;
+    }
+
+    for (number, record) in session.history().iter().enumerate() {
+        writeln!(output, "{}. {record}", number + 1)?;
+    }
+
+    Ok(())
+}
+
+// the fahrenheit to celsius converter, reading from and writing to anything so it can be tested
+pub fn run_convert<R: BufRead, W: Write>(input: R, output: W) -> io::Result<()> {
+    let mut session = Session::default();
+    convert_loop(input, output, &mut session, Unit::Fahrenheit, Unit::Celsius, DEFAULT_PRECISION)
+}
+
+// runs the converter loop on the terminal with a fresh session
+fn convert_stdin(from: Unit, to: Unit) {
+    let mut session = Session::default();
+    convert_loop(io::stdin().lock(), io::stdout(), &mut session, from, to, DEFAULT_PRECISION)
         .expect("Failed to read line");
+}
+
+pub fn temp_convert(){
+    run_convert(io::stdin().lock(), io::stdout()).expect("Failed to read line");
+}
+
+pub fn temp_convert_c_to_f(){
+    convert_stdin(Unit::Celsius, Unit::Fahrenheit);
+}
+
+pub fn temp_convert_f_to_k(){
+    convert_stdin(Unit::Fahrenheit, Unit::Kelvin);
+}
+
+pub fn temp_convert_c_to_k(){
+    convert_stdin(Unit::Celsius, Unit::Kelvin);
+}
+
+// asks which way to convert, reprompting until a valid choice is given
+// with max_kelvin set the session is strict, see Session::strict
+pub fn run_converter(max_kelvin: Option<f64>) {
+    run_converter_with(io::stdin().lock(), io::stdout(), max_kelvin).expect("Failed to read line");
+}
+
+fn run_converter_with<R: BufRead, W: Write>(
+    mut input: R,
+    mut output: W,
+    max_kelvin: Option<f64>,
+) -> io::Result<()> {
+    let direction = loop {
+        let prompt = "Which conversion?\n  1) F to C\n  2) C to F\n  3) F to K\n  4) C to K";
+        let Some(choice) = read_input(&mut input, &mut output, prompt)? else {
+            return Ok(());
+        };
 
-    let degf: i32 = match degf.trim().parse() {
-        Ok(num) => num,
-        Err(_) => -1,
+        match parse_direction(&choice) {
+            Some(direction) => break direction,
+            None => writeln!(output, "'{}' is not a valid choice", choice.trim())?,
+        }
     };
 
-    let degc:i32 = (degf-32)*5/9;
+    let precision = loop {
+        let prompt = "How many decimal places? (blank for 2)";
+        let Some(line) = read_input(&mut input, &mut output, prompt)? else {
+            return Ok(());
+        };
+
+        match parse_precision(&line) {
+            Some(precision) => break precision,
+            None => writeln!(output, "'{}' is not a valid number of decimal places", line.trim())?,
+        }
+    };
+
+    let (from, to) = direction.units();
+    let mut session = match max_kelvin {
+        Some(max) => Session::default().strict(max),
+        None => Session::default(),
+    };
+    convert_loop(input, output, &mut session, from, to, precision)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FibError {
+    Overflow,
+}
+
+impl fmt::Display for FibError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FibError::Overflow => write!(f, "that fibonacci number is too big to fit in a u64"),
+        }
+    }
+}
+
+// generates the nth fibonacci number, counting fib(0) = 0 and fib(1) = 1
+// done with a loop instead of recursion so large n doesn't blow up the stack or take forever
+// fib(93) is the biggest that fits in a u64, past that checked_add catches the overflow
+pub fn fibonacci(n: u32) -> Result<u64, FibError> {
+    if n == 0 {
+        return Ok(0);
+    }
+
+    let mut previous: u64 = 0;
+    let mut current: u64 = 1;
+
+    for _ in 1..n {
+        let next = previous.checked_add(current).ok_or(FibError::Overflow)?;
+        previous = current;
+        current = next;
+    }
+
+    Ok(current)
+}
+
+// an iterator over the fibonacci numbers, i.e. `Fib::new().take(10).collect()`
+// a is the next value to hand out and b the one after it, either becomes None once it
+// would overflow, so the iterator ends after fib(93) instead of panicking
+pub struct Fib {
+    a: Option<u64>,
+    b: Option<u64>,
+}
+
+impl Fib {
+    pub fn new() -> Fib {
+        Fib { a: Some(0), b: Some(1) }
+    }
+}
+
+impl Default for Fib {
+    fn default() -> Self {
+        Fib::new()
+    }
+}
+
+impl Iterator for Fib {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.a?;
+
+        self.a = self.b;
+        self.b = self.b.and_then(|b| current.checked_add(b));
+
+        Some(current)
+    }
+}
+
+// remembers every fibonacci number it has worked out so repeat calls are just a lookup
+// unlike fibonacci() this keeps state between calls, which is why get() needs &mut self
+// n past 93 doesn't fit in a u64, so get() gives FibError::Overflow like fibonacci() does
+pub struct FibCache {
+    cache: Vec<u64>,
+}
+
+impl FibCache {
+    pub fn new() -> FibCache {
+        FibCache { cache: vec![0, 1] }
+    }
+
+    pub fn get(&mut self, n: usize) -> Result<u64, FibError> {
+        // only the missing entries are computed, starting from the end of the cache
+        // everything up to the overflow stays cached, so a later smaller n is still a lookup
+        while self.cache.len() <= n {
+            let len = self.cache.len();
+            let next = self.cache[len - 1]
+                .checked_add(self.cache[len - 2])
+                .ok_or(FibError::Overflow)?;
+            self.cache.push(next);
+        }
+
+        Ok(self.cache[n])
+    }
+
+    // every value worked out so far, index n holds fib(n)
+    pub fn cached(&self) -> &[u64] {
+        &self.cache
+    }
+}
+
+impl Default for FibCache {
+    fn default() -> Self {
+        FibCache::new()
+    }
+}
+
+// the classic fizzbuzz for 1 through n: "Fizz" for multiples of 3, "Buzz" for multiples of 5,
+// "FizzBuzz" for both, and the number itself otherwise
+pub fn fizzbuzz(n: u32) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for i in 1..=n {
+        let line = match (i % 3, i % 5) {
+            (0, 0) => String::from("FizzBuzz"),
+            (0, _) => String::from("Fizz"),
+            (_, 0) => String::from("Buzz"),
+            _ => i.to_string(),
+        };
+        lines.push(line);
+    }
+
+    lines
+}
+
+// builds the lyrics to "The Twelve Days of Christmas", one verse per day
+// each verse counts back down through every gift received so far
+pub fn twelve_days() -> String {
+    let days = [
+        "first", "second", "third", "fourth", "fifth", "sixth",
+        "seventh", "eighth", "ninth", "tenth", "eleventh", "twelfth",
+    ];
+    let gifts = [
+        "a partridge in a pear tree",
+        "two turtle doves",
+        "three French hens",
+        "four calling birds",
+        "five gold rings",
+        "six geese a-laying",
+        "seven swans a-swimming",
+        "eight maids a-milking",
+        "nine ladies dancing",
+        "ten lords a-leaping",
+        "eleven pipers piping",
+        "twelve drummers drumming",
+    ];
+
+    let mut song = String::new();
+
+    for (day, ordinal) in days.iter().enumerate() {
+        if day > 0 {
+            song.push('\n');
+        }
+        song.push_str(&format!("On the {ordinal} day of Christmas my true love sent to me:\n"));
+
+        for gift in (0..=day).rev() {
+            // only the very first verse has the partridge on its own
+            if gift == 0 && day > 0 {
+                song.push_str("and ");
+            }
+            song.push_str(gifts[gift]);
+            song.push('\n');
+        }
+    }
 
-    println!("{degf} -> {degc}");
-}
\ No newline at end of file
+    song
+}
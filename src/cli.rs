@@ -0,0 +1,522 @@
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use crate::config::Config;
+use crate::error::TempError;
+use crate::temperature::{
+    convert_f32, convert_f64, describe, format_result, parse_temperature, round_to, round_with,
+    parse_max_kelvin, RoundMode, Unit, DEFAULT_PRECISION, MAX_PRECISION, SUN_SURFACE_KELVIN,
+};
+
+// everything needed to run a single conversion from the command line
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvertRequest {
+    pub value: f64,
+    pub from: Unit,
+    pub to: Unit,
+    pub precision: u32,
+    pub round_mode: RoundMode,
+    pub quiet: bool,
+    pub format: OutputFormat,
+    pub explain: bool,
+    pub color: bool,
+    pub log: Option<PathBuf>,
+    pub float_width: FloatWidth,
+    pub max_kelvin: Option<f64>,
+    pub round_trip_check: bool,
+}
+
+// which float type the conversion arithmetic is done in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatWidth {
+    F32,
+    F64,
+}
+
+// how the result of a conversion gets printed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl ConvertRequest {
+    // a request with every optional setting left at its default
+    pub fn new(value: f64, from: Unit, to: Unit) -> ConvertRequest {
+        ConvertRequest {
+            value,
+            from,
+            to,
+            precision: DEFAULT_PRECISION,
+            round_mode: RoundMode::Nearest,
+            quiet: false,
+            format: OutputFormat::Text,
+            explain: false,
+            color: true,
+            log: None,
+            float_width: FloatWidth::F64,
+            max_kelvin: None,
+            round_trip_check: false,
+        }
+    }
+
+    // starts a builder, i.e. `ConvertRequest::builder().value(98.6).from(f).to(c).build()`
+    pub fn builder() -> ConvertRequestBuilder {
+        ConvertRequestBuilder::default()
+    }
+
+    // converts the request's value in its float width, f32 results are widened back to f64
+    pub fn convert(&self) -> f64 {
+        match self.float_width {
+            FloatWidth::F32 => convert_f32(self.value as f32, self.from, self.to) as f64,
+            FloatWidth::F64 => convert_f64(self.value, self.from, self.to),
+        }
+    }
+
+    // rounds a result to this request's precision using its rounding mode
+    pub fn round(&self, result: f64) -> f64 {
+        round_with(result, self.precision, self.round_mode)
+    }
+
+    // with --round-trip-check, a warning when the rounded result converts back more than
+    // ROUNDTRIP_WARN_THRESHOLD away from the value that was typed in
+    // the result is converted and rounded the same way as the one that gets printed, so the
+    // float width and rounding mode count too
+    pub fn roundtrip_warning(&self) -> Option<String> {
+        if !self.round_trip_check {
+            return None;
+        }
+
+        let shown = self.round(self.convert());
+        let delta = (convert_f64(shown, self.to, self.from) - self.value).abs();
+        (delta > ROUNDTRIP_WARN_THRESHOLD).then(|| {
+            format!(
+                "warning: rounding to {} decimals loses {} {} when converted back",
+                self.precision,
+                round_to(delta, DEFAULT_PRECISION),
+                self.from.name()
+            )
+        })
+    }
+}
+
+// builds a ConvertRequest one setting at a time, value, from, and to have to be set
+// while everything else falls back to the same defaults as ConvertRequest::new
+#[derive(Debug, Default)]
+pub struct ConvertRequestBuilder {
+    value: Option<f64>,
+    from: Option<Unit>,
+    to: Option<Unit>,
+    precision: Option<u32>,
+    quiet: bool,
+}
+
+impl ConvertRequestBuilder {
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn from(mut self, unit: Unit) -> Self {
+        self.from = Some(unit);
+        self
+    }
+
+    pub fn to(mut self, unit: Unit) -> Self {
+        self.to = Some(unit);
+        self
+    }
+
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    pub fn quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    // fails with the same errors parse_args gives when the value or a unit is missing
+    pub fn build(self) -> Result<ConvertRequest, ArgError> {
+        let value = self.value.ok_or(ArgError::MissingValue)?;
+        let from = self.from.ok_or(ArgError::MissingFlag("--from"))?;
+        let to = self.to.ok_or(ArgError::MissingFlag("--to"))?;
+        let precision = self.precision.unwrap_or(DEFAULT_PRECISION);
+        if precision > MAX_PRECISION {
+            return Err(ArgError::InvalidPrecision(precision.to_string()));
+        }
+
+        Ok(ConvertRequest {
+            precision,
+            quiet: self.quiet,
+            ..ConvertRequest::new(value, from, to)
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ArgError {
+    MissingValue,
+    InvalidValue(String),
+    MissingFlag(&'static str),
+    MissingUnit(&'static str),
+    MissingPath(&'static str),
+    InvalidPrecision(String),
+    UnknownRoundMode(String),
+    UnknownUnit(String),
+    UnknownFormat(String),
+    UnknownFloatWidth(String),
+    UnexpectedArgument(String),
+    DuplicateFlag(&'static str),
+    InvalidMaxKelvin(String),
+    WrongTokenCount(usize),
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgError::MissingValue => write!(f, "no temperature value was given"),
+            ArgError::InvalidValue(value) => write!(f, "'{value}' is not a valid temperature"),
+            ArgError::MissingFlag(flag) => write!(f, "missing required flag {flag}"),
+            ArgError::MissingUnit(flag) => write!(f, "{flag} needs a unit after it"),
+            ArgError::MissingPath(flag) => write!(f, "{flag} needs a file path after it"),
+            ArgError::InvalidPrecision(precision) => {
+                write!(f, "'{precision}' is not a valid number of decimal places, ")?;
+                write!(f, "use 0 to {MAX_PRECISION}")
+            }
+            ArgError::UnknownRoundMode(mode) => {
+                write!(f, "'{mode}' is not a rounding mode, use nearest, floor, or ceil")
+            }
+            ArgError::UnknownUnit(unit) => {
+                write!(f, "'{unit}' is not a known unit, use c, f, k, r, n, or re")
+            }
+            ArgError::UnknownFormat(format) => {
+                write!(f, "'{format}' is not a known format, use text or json")
+            }
+            ArgError::UnknownFloatWidth(width) => {
+                write!(f, "'{width}' is not a float precision, use f32 or f64")
+            }
+            ArgError::UnexpectedArgument(arg) => write!(f, "unexpected argument '{arg}'"),
+            ArgError::DuplicateFlag(flag) => write!(f, "{flag} was given more than once"),
+            ArgError::InvalidMaxKelvin(max) => {
+                write!(f, "'{max}' is not a valid maximum, use a number of kelvin above 0")
+            }
+            ArgError::WrongTokenCount(count) => {
+                write!(f, "expected a value and two units like '98.6 f c', got {count} pieces")
+            }
+        }
+    }
+}
+
+// how far a round trip may drift, in the input's unit, before --round-trip-check warns
+pub const ROUNDTRIP_WARN_THRESHOLD: f64 = 0.1;
+
+// flags that may only be given once, with two it would be a guess which one was meant
+const ONCE_ONLY_FLAGS: [&str; 7] =
+    ["--from", "--to", "--decimals", "--precision", "--format", "--round", "--log"];
+
+fn parse_unit(unit: &str) -> Result<Unit, ArgError> {
+    unit.parse().map_err(|_| ArgError::UnknownUnit(unit.to_string()))
+}
+
+// parses a whole request from one line like "98.6 f c", i.e. a line from a batch file
+// the value comes first, then the unit to convert from, then the unit to convert to
+impl FromStr for ConvertRequest {
+    type Err = ArgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        let [value, from, to] = tokens[..] else {
+            return Err(ArgError::WrongTokenCount(tokens.len()));
+        };
+
+        let value =
+            parse_temperature(value).map_err(|_| ArgError::InvalidValue(value.to_string()))?;
+        Ok(ConvertRequest::new(value, parse_unit(from)?, parse_unit(to)?))
+    }
+}
+
+// what the command line asked for, a conversion or one of the flags that just print something
+#[derive(Debug, PartialEq)]
+pub enum ParsedArgs {
+    Convert(ConvertRequest),
+    Help,
+    Version,
+}
+
+// parses arguments like `98.6 --from f --to c`, without the program name
+// `--help`/`-h` and `--version`/`-V` skip everything else and don't need a value
+// `--decimals N` is optional and defaults to DEFAULT_PRECISION
+// `--round nearest|floor|ceil` picks how the result is rounded, defaulting to nearest
+// `--quiet` or `-q` prints just the number so the output can be piped into other tools
+// `--format json` prints the whole conversion as a JSON object instead
+// `--explain` also prints the formula that was used
+// `--no-color` turns off the colored result
+// `--log PATH` appends every conversion to the file at PATH
+// `--strict` rejects values hotter than the surface of the sun as likely typos
+// `--max-kelvin K` is --strict with a different maximum, given in kelvin
+// `--precision f32|f64` picks the float type the math is done in, defaulting to f64
+// the difference only shows past the default 2 decimals, so pair f32 with i.e. `--decimals 8`
+pub fn parse_args(args: &[String]) -> Result<ParsedArgs, ArgError> {
+    parse_args_with(args, None)
+}
+
+// like parse_args, but the config's units, precision, and max_kelvin are used when their flags
+// aren't given, so with a config `--from` and `--to` can be left off
+// the config's color mode is left to whoever prints the result, since it depends on stdout
+pub fn parse_args_with(args: &[String], config: Option<&Config>) -> Result<ParsedArgs, ArgError> {
+    // these win over everything else, even a missing value or a bad flag
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        return Ok(ParsedArgs::Help);
+    }
+    if args.iter().any(|arg| arg == "--version" || arg == "-V") {
+        return Ok(ParsedArgs::Version);
+    }
+
+    let mut value = None;
+    let mut from = config.map(|config| config.from);
+    let mut to = config.map(|config| config.to);
+    let mut precision = config.map_or(DEFAULT_PRECISION, |config| config.precision);
+    let mut round_mode = RoundMode::Nearest;
+    let mut quiet = false;
+    let mut format = OutputFormat::Text;
+    let mut explain = false;
+    let mut color = true;
+    let mut log = None;
+    let mut float_width = FloatWidth::F64;
+    let mut max_kelvin = None;
+    let mut round_trip_check = false;
+
+    let mut seen = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if let Some(&flag) = ONCE_ONLY_FLAGS.iter().find(|flag| **flag == arg) {
+            if seen.contains(&flag) {
+                return Err(ArgError::DuplicateFlag(flag));
+            }
+            seen.push(flag);
+        }
+
+        match arg.as_str() {
+            "--from" => {
+                let unit = args.next().ok_or(ArgError::MissingUnit("--from"))?;
+                from = Some(parse_unit(unit)?);
+            }
+            "--to" => {
+                let unit = args.next().ok_or(ArgError::MissingUnit("--to"))?;
+                to = Some(parse_unit(unit)?);
+            }
+            "--decimals" => {
+                let decimals = args.next().ok_or(ArgError::InvalidPrecision(String::new()))?;
+                precision = decimals
+                    .parse()
+                    .ok()
+                    .filter(|&precision| precision <= MAX_PRECISION)
+                    .ok_or_else(|| ArgError::InvalidPrecision(decimals.to_string()))?;
+            }
+            "--round" => {
+                let mode = args.next().ok_or(ArgError::UnknownRoundMode(String::new()))?;
+                round_mode = match mode.to_lowercase().as_str() {
+                    "nearest" => RoundMode::Nearest,
+                    "floor" => RoundMode::Floor,
+                    "ceil" => RoundMode::Ceil,
+                    _ => return Err(ArgError::UnknownRoundMode(mode.to_string())),
+                };
+            }
+            "--quiet" | "-q" => quiet = true,
+            "--explain" => explain = true,
+            "--no-color" => color = false,
+            "--strict" => set_max_kelvin(&mut max_kelvin, SUN_SURFACE_KELVIN)?,
+            "--max-kelvin" => set_max_kelvin(&mut max_kelvin, parse_max_kelvin_flag(args.next())?)?,
+            "--round-trip-check" => round_trip_check = true,
+            "--precision" => {
+                let width = args.next().ok_or(ArgError::UnknownFloatWidth(String::new()))?;
+                float_width = match width.to_lowercase().as_str() {
+                    "f32" => FloatWidth::F32,
+                    "f64" => FloatWidth::F64,
+                    _ => return Err(ArgError::UnknownFloatWidth(width.to_string())),
+                };
+            }
+            "--log" => {
+                let path = args.next().ok_or(ArgError::MissingPath("--log"))?;
+                log = Some(PathBuf::from(path));
+            }
+            "--format" => {
+                let name = args.next().ok_or(ArgError::UnknownFormat(String::new()))?;
+                format = match name.to_lowercase().as_str() {
+                    "text" => OutputFormat::Text,
+                    "json" => OutputFormat::Json,
+                    _ => return Err(ArgError::UnknownFormat(name.to_string())),
+                };
+            }
+            _ if value.is_none() => match parse_temperature(arg) {
+                Ok(num) => value = Some(num),
+                Err(_) => return Err(ArgError::InvalidValue(arg.to_string())),
+            },
+            _ => return Err(ArgError::UnexpectedArgument(arg.to_string())),
+        }
+    }
+
+    Ok(ParsedArgs::Convert(ConvertRequest {
+        precision,
+        round_mode,
+        quiet,
+        format,
+        explain,
+        color,
+        log,
+        float_width,
+        max_kelvin: max_kelvin.or(config.and_then(|config| config.max_kelvin)),
+        round_trip_check,
+        ..ConvertRequest::new(
+            value.ok_or(ArgError::MissingValue)?,
+            from.ok_or(ArgError::MissingFlag("--from"))?,
+            to.ok_or(ArgError::MissingFlag("--to"))?,
+        )
+    }))
+}
+
+// --strict and --max-kelvin both set the maximum, so only one of them may be given, once
+fn set_max_kelvin(max_kelvin: &mut Option<f64>, max: f64) -> Result<(), ArgError> {
+    match max_kelvin.replace(max) {
+        Some(_) => Err(ArgError::DuplicateFlag("--strict or --max-kelvin")),
+        None => Ok(()),
+    }
+}
+
+fn parse_max_kelvin_flag(max: Option<&String>) -> Result<f64, ArgError> {
+    let max = max.ok_or(ArgError::InvalidMaxKelvin(String::new()))?;
+    parse_max_kelvin(max).ok_or_else(|| ArgError::InvalidMaxKelvin(max.to_string()))
+}
+
+// takes `--strict` and `--max-kelvin K` out of args for the modes that read their values from
+// stdin or a file instead of the command line, giving back the maximum and the other args
+// with neither flag the maximum is None, and the caller falls back to the config's
+pub fn split_strict_flags(args: &[String]) -> Result<(Option<f64>, Vec<&String>), ArgError> {
+    let mut max_kelvin = None;
+    let mut rest = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strict" => set_max_kelvin(&mut max_kelvin, SUN_SURFACE_KELVIN)?,
+            "--max-kelvin" => set_max_kelvin(&mut max_kelvin, parse_max_kelvin_flag(args.next())?)?,
+            _ => rest.push(arg),
+        }
+    }
+
+    Ok((max_kelvin, rest))
+}
+
+// reads a request from the TEMP_VALUE, TEMP_FROM, and TEMP_TO environment variables
+// gives None unless all three are set
+pub fn from_env() -> Option<Result<ConvertRequest, TempError>> {
+    from_vars(|name| env::var(name).ok())
+}
+
+// does the work for from_env, looking each variable up through `var` so it can be tested
+// without touching the real environment
+pub fn from_vars<F>(var: F) -> Option<Result<ConvertRequest, TempError>>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let value = var("TEMP_VALUE")?;
+    let from = var("TEMP_FROM")?;
+    let to = var("TEMP_TO")?;
+
+    let parse = || {
+        Ok(ConvertRequest::new(parse_temperature(&value)?, from.parse()?, to.parse()?))
+    };
+    Some(parse())
+}
+
+// formats a finished conversion the way it should be printed, i.e. "98.6°F -> 37.00°C"
+// the result always shows req.precision decimals, see format_result
+pub fn format_output(req: &ConvertRequest, result: f64) -> String {
+    let result = req.round(result);
+    let precision = req.precision as usize;
+
+    if req.format == OutputFormat::Json {
+        to_json(req, result)
+    } else if req.quiet {
+        format_result(result, req.precision)
+    } else {
+        let to = req.to.temperature(result);
+        let to = if req.color {
+            colorize(&format!("{to:.precision$}"), to.to_celsius())
+        } else {
+            format!("{to:.precision$}")
+        };
+        format!("{} -> {to}", req.from.temperature(req.value))
+    }
+}
+
+// wraps the text in an ANSI color picked from describe(): blue for freezing and cold,
+// green for mild, and red for warm and hot
+pub fn colorize(text: &str, celsius: f64) -> String {
+    let code = match describe(celsius) {
+        "freezing" | "cold" => "34",
+        "mild" => "32",
+        _ => "31",
+    };
+
+    format!("\x1b[{code}m{text}\x1b[0m")
+}
+
+// builds the JSON by hand, unit names are plain ascii so nothing needs escaping
+// {:?} is used for the numbers so whole values keep their ".0", i.e. 37.0 instead of 37
+pub fn to_json(req: &ConvertRequest, result: f64) -> String {
+    format!(
+        "{{\"input\":{:?},\"from\":\"{}\",\"to\":\"{}\",\"result\":{:?}}}",
+        req.value,
+        req.from.name(),
+        req.to.name(),
+        result
+    )
+}
+
+// shows the formula for the conversion with the numbers filled in,
+// i.e. "C = (98.6 - 32) × 5/9 = 37.00"
+// newton and réaumur are only written relative to celsius, so the other units are
+// shown going through their celsius value first
+pub fn explain(req: &ConvertRequest, result: f64) -> String {
+    let v = req.value;
+    let c = format_result(req.round(req.from.temperature(v).to_celsius()), req.precision);
+    let formula = match (req.from, req.to) {
+        (Unit::Fahrenheit, Unit::Celsius) => format!("({v} - 32) × 5/9"),
+        (Unit::Fahrenheit, Unit::Kelvin) => format!("({v} - 32) × 5/9 + 273.15"),
+        (Unit::Fahrenheit, Unit::Rankine) => format!("{v} + 459.67"),
+        (Unit::Celsius, Unit::Fahrenheit) => format!("{v} × 9/5 + 32"),
+        (Unit::Celsius, Unit::Kelvin) => format!("{v} + 273.15"),
+        (Unit::Celsius, Unit::Rankine) => format!("({v} + 273.15) × 9/5"),
+        (Unit::Kelvin, Unit::Celsius) => format!("{v} - 273.15"),
+        (Unit::Kelvin, Unit::Fahrenheit) => format!("({v} - 273.15) × 9/5 + 32"),
+        (Unit::Kelvin, Unit::Rankine) => format!("{v} × 9/5"),
+        (Unit::Rankine, Unit::Celsius) => format!("{v} × 5/9 - 273.15"),
+        (Unit::Rankine, Unit::Fahrenheit) => format!("{v} - 459.67"),
+        (Unit::Rankine, Unit::Kelvin) => format!("{v} × 5/9"),
+        (Unit::Celsius, Unit::Newton) => format!("{v} × 33/100"),
+        (Unit::Celsius, Unit::Reaumur) => format!("{v} × 4/5"),
+        (Unit::Newton, Unit::Celsius) => format!("{v} × 100/33"),
+        (Unit::Reaumur, Unit::Celsius) => format!("{v} × 5/4"),
+        (from, Unit::Newton) if from != Unit::Newton => format!("{c} × 33/100"),
+        (from, Unit::Reaumur) if from != Unit::Reaumur => format!("{c} × 4/5"),
+        (Unit::Newton | Unit::Reaumur, Unit::Fahrenheit) => format!("{c} × 9/5 + 32"),
+        (Unit::Newton | Unit::Reaumur, Unit::Kelvin) => format!("{c} + 273.15"),
+        (Unit::Newton | Unit::Reaumur, Unit::Rankine) => format!("({c} + 273.15) × 9/5"),
+        _ => format!("{v}"),
+    };
+
+    let letter = match req.to {
+        Unit::Celsius => "C",
+        Unit::Fahrenheit => "F",
+        Unit::Kelvin => "K",
+        Unit::Rankine => "R",
+        Unit::Newton => "N",
+        Unit::Reaumur => "Ré",
+    };
+
+    format!("{letter} = {formula} = {}", format_result(req.round(result), req.precision))
+}
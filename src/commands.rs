@@ -0,0 +1,95 @@
+use crate::ch3_practice::help_text;
+
+// what running a command asks the converter loop to do next
+#[derive(Debug, PartialEq)]
+pub enum CommandResult {
+    Output(String),
+    Failed(String),
+    Quit,
+}
+
+// a word the converter loop understands, like `help` or `quit`
+// anything that implements this can be registered, so new commands don't mean editing the loop
+pub trait Command {
+    fn name(&self) -> &str;
+
+    // arg is whatever was typed after the name, trimmed, so "help" gives ""
+    fn run(&self, arg: &str) -> CommandResult;
+
+    // other spellings can be picked up by overriding this, see HelpCommand
+    fn matches(&self, name: &str) -> bool {
+        self.name() == name
+    }
+}
+
+pub struct HelpCommand;
+
+impl Command for HelpCommand {
+    fn name(&self) -> &str {
+        "help"
+    }
+
+    fn run(&self, _arg: &str) -> CommandResult {
+        CommandResult::Output(help_text().to_string())
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        name == "help" || name == "?"
+    }
+}
+
+pub struct QuitCommand;
+
+impl Command for QuitCommand {
+    fn name(&self) -> &str {
+        "quit"
+    }
+
+    fn run(&self, _arg: &str) -> CommandResult {
+        CommandResult::Quit
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        name == "quit" || name == "q"
+    }
+}
+
+// the commands the loop checks a line against before treating it as a temperature
+// Box<dyn Command> lets commands of different types sit in the same Vec
+pub struct CommandRegistry {
+    commands: Vec<Box<dyn Command>>,
+}
+
+impl CommandRegistry {
+    // a registry with no commands at all, use default() for help and quit
+    pub fn new() -> CommandRegistry {
+        CommandRegistry { commands: Vec::new() }
+    }
+
+    // a later command with the same name doesn't replace an earlier one, the first match wins
+    pub fn register(&mut self, command: Box<dyn Command>) {
+        self.commands.push(command);
+    }
+
+    pub fn find(&self, name: &str) -> Option<&dyn Command> {
+        self.commands.iter().find(|command| command.matches(name)).map(|command| command.as_ref())
+    }
+
+    // runs the command named by the first word of the line, ignoring case
+    // None means no command has that name, so the line is probably a temperature
+    pub fn dispatch(&self, line: &str) -> Option<CommandResult> {
+        let line = line.trim();
+        let (name, arg) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let command = self.find(&name.to_lowercase())?;
+        Some(command.run(arg.trim()))
+    }
+}
+
+impl Default for CommandRegistry {
+    fn default() -> Self {
+        let mut registry = CommandRegistry::new();
+        registry.register(Box::new(HelpCommand));
+        registry.register(Box::new(QuitCommand));
+        registry
+    }
+}
@@ -0,0 +1,36 @@
+// runnable pieces of chapter 3's notes, see examples/common_concepts.rs for the rest
+
+// what 255u8 + 1 gives with each of the explicit overflow methods, as
+// (wrapping, saturating, checked)
+// plain `+` panics on overflow in a debug build and wraps in a release build, so when
+// overflow is possible one of these should be picked on purpose:
+// - wrapping_add goes around past the max, 255 + 1 is 0
+// - saturating_add sticks at the max, 255 + 1 is 255
+// - checked_add gives None instead of a wrong number
+pub fn demo_overflow() -> (u8, u8, Option<u8>) {
+    let max = u8::MAX;
+
+    (max.wrapping_add(1), max.saturating_add(1), max.checked_add(1))
+}
+
+// the spaces example from the book, where shadowing lets one name change type
+// returns the final value so the result of all the shadowing can be checked
+pub fn shadowing_demo() -> usize {
+    // step 1: spaces starts out as a &str
+    let spaces = "   ";
+
+    // step 2: a new `spaces` shadows the old one, this time the usize length of the &str
+    // with `let mut spaces = "   "; spaces = spaces.len();` this would be a type error instead
+    let spaces = spaces.len();
+
+    // step 3: shadowing again can still do math on the number, the &str is gone for good
+    let spaces = spaces * 2;
+
+    // step 4: an inner scope can shadow it too, but only until the scope ends
+    {
+        let spaces = spaces + 100;
+        debug_assert_eq!(spaces, 106);
+    }
+
+    spaces
+}
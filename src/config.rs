@@ -0,0 +1,156 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use crate::error::TempError;
+use crate::temperature::{parse_max_kelvin, Unit, DEFAULT_PRECISION, MAX_PRECISION};
+
+// the file the converter looks for in the current directory
+pub const CONFIG_FILE: &str = ".tempconfig";
+
+// defaults for the converter, used whenever a flag isn't given on the command line
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub from: Unit,
+    pub to: Unit,
+    pub precision: u32,
+    pub color: ColorMode,
+    // strict mode's maximum, see check_range
+    pub max_kelvin: Option<f64>,
+}
+
+// when results get colored, auto only colors them when stdout is a terminal
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    pub fn use_color(self, is_terminal: bool) -> bool {
+        match self {
+            ColorMode::Auto => is_terminal,
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+}
+
+// fahrenheit to celsius at DEFAULT_PRECISION, colored when it's going to a terminal
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            from: Unit::Fahrenheit,
+            to: Unit::Celsius,
+            precision: DEFAULT_PRECISION,
+            color: ColorMode::Auto,
+            max_kelvin: None,
+        }
+    }
+}
+
+// the settings a config file actually mentions, None for every key it leaves out
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PartialConfig {
+    pub from: Option<Unit>,
+    pub to: Option<Unit>,
+    pub precision: Option<u32>,
+    pub color: Option<ColorMode>,
+    pub max_kelvin: Option<f64>,
+}
+
+// lays the overrides on top of base, anything the overrides don't set comes from base
+pub fn merge(base: Config, overrides: PartialConfig) -> Config {
+    Config {
+        from: overrides.from.unwrap_or(base.from),
+        to: overrides.to.unwrap_or(base.to),
+        precision: overrides.precision.unwrap_or(base.precision),
+        color: overrides.color.unwrap_or(base.color),
+        max_kelvin: overrides.max_kelvin.or(base.max_kelvin),
+    }
+}
+
+// reads a config file of `key=value` lines, i.e.
+//
+//     from=f
+//     to=c
+//     precision=1
+//     color=never
+//     max_kelvin=5778
+//
+// keys left out keep Config's defaults, unknown keys print a warning to stderr
+pub fn load_config(path: &Path) -> Result<Config, TempError> {
+    let file = File::open(path)?;
+    parse_config(BufReader::new(file), io::stderr())
+}
+
+// does the work for load_config, writing warnings to `warnings` so they can be tested
+pub fn parse_config<R: BufRead, W: Write>(reader: R, warnings: W) -> Result<Config, TempError> {
+    Ok(merge(Config::default(), parse_partial_config(reader, warnings)?))
+}
+
+// reads just the keys that are set, without filling in the defaults
+// blank lines and lines starting with # are skipped, anything else has to be `key=value`
+// and errors carry the 1-based line number they came from
+pub fn parse_partial_config<R: BufRead, W: Write>(
+    reader: R,
+    mut warnings: W,
+) -> Result<PartialConfig, TempError> {
+    let mut config = PartialConfig::default();
+
+    for (index, line) in reader.lines().enumerate() {
+        let number = index + 1;
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let at_line = |err| TempError::Line(number, Box::new(err));
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(at_line(TempError::InvalidConfig(format!("expected key=value, got '{line}'"))));
+        };
+        let (key, value) = (key.trim(), value.trim());
+
+        match key {
+            "from" => config.from = Some(value.parse().map_err(at_line)?),
+            "to" => config.to = Some(value.parse().map_err(at_line)?),
+            "precision" => {
+                let precision = value
+                    .parse()
+                    .ok()
+                    .filter(|&precision| precision <= MAX_PRECISION)
+                    .ok_or_else(|| {
+                        let reason =
+                            format!("'{value}' is not a valid precision, use 0 to {MAX_PRECISION}");
+                        at_line(TempError::InvalidConfig(reason))
+                    })?;
+                config.precision = Some(precision);
+            }
+            // true and false still work from before auto existed
+            "color" => {
+                let color = match value.to_lowercase().as_str() {
+                    "auto" => ColorMode::Auto,
+                    "always" | "true" => ColorMode::Always,
+                    "never" | "false" => ColorMode::Never,
+                    _ => {
+                        let reason = format!("color has to be auto, always, or never, got '{value}'");
+                        return Err(at_line(TempError::InvalidConfig(reason)));
+                    }
+                };
+                config.color = Some(color);
+            }
+            "max_kelvin" => {
+                let max = parse_max_kelvin(value).ok_or_else(|| {
+                    let reason = format!("max_kelvin has to be a number above 0, got '{value}'");
+                    at_line(TempError::InvalidConfig(reason))
+                })?;
+                config.max_kelvin = Some(max);
+            }
+            _ => writeln!(warnings, "warning: line {number}: ignoring unknown key '{key}'")?,
+        }
+    }
+
+    Ok(config)
+}
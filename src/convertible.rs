@@ -0,0 +1,37 @@
+// anything measured on a scale that can be turned into a shared base unit and back
+// i.e. temperatures go through celsius, so any two of them can convert without knowing
+// about each other
+pub trait Convertible {
+    fn to_base(&self) -> f64;
+    fn from_base(base: f64) -> Self;
+}
+
+// converts between any two Convertible types by going through their base unit,
+// i.e. `let c: Celsius = convert(Fahrenheit(212.0));`
+// both types have to share the same base, which the trait can't check on its own
+pub fn convert<A: Convertible, B: Convertible>(a: A) -> B {
+    B::from_base(a.to_base())
+}
+
+// a family of units that all convert through one base unit with an affine formula:
+// base = (value + offset) × to_base_factor
+// lengths and masses only scale so their offset is 0, temperatures need it for things like
+// fahrenheit's 32
+pub trait UnitKind: Copy + PartialEq {
+    fn to_base_factor(self) -> f64;
+
+    fn offset(self) -> f64 {
+        0.0
+    }
+}
+
+// converts between two units of the same family, i.e. feet to meters or fahrenheit to kelvin
+// the value is taken to the base unit and back out, mixing families won't compile
+pub fn convert_within<U: UnitKind>(value: f64, from: U, to: U) -> f64 {
+    if from == to {
+        return value;
+    }
+
+    let base = (value + from.offset()) * from.to_base_factor();
+    base / to.to_base_factor() - to.offset()
+}
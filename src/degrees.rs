@@ -0,0 +1,85 @@
+use std::ops::{Add, Sub};
+use crate::convertible::Convertible;
+use crate::temperature::{celsius_to_fahrenheit, fahrenheit_to_celsius};
+
+/// a reading in degrees celsius that can't be mixed up with a fahrenheit one
+///
+/// celsius only adds to and subtracts from celsius, so mixing units won't compile:
+///
+/// ```compile_fail
+/// use learning_rust::degrees::{Celsius, Fahrenheit};
+///
+/// let total = Celsius(10.0) + Fahrenheit(50.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Celsius(pub f64);
+
+// a reading in degrees fahrenheit, the same idea as Celsius
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Fahrenheit(pub f64);
+
+impl Add for Celsius {
+    type Output = Celsius;
+
+    fn add(self, other: Celsius) -> Celsius {
+        Celsius(self.0 + other.0)
+    }
+}
+
+impl Sub for Celsius {
+    type Output = Celsius;
+
+    fn sub(self, other: Celsius) -> Celsius {
+        Celsius(self.0 - other.0)
+    }
+}
+
+impl Add for Fahrenheit {
+    type Output = Fahrenheit;
+
+    fn add(self, other: Fahrenheit) -> Fahrenheit {
+        Fahrenheit(self.0 + other.0)
+    }
+}
+
+impl Sub for Fahrenheit {
+    type Output = Fahrenheit;
+
+    fn sub(self, other: Fahrenheit) -> Fahrenheit {
+        Fahrenheit(self.0 - other.0)
+    }
+}
+
+// the only way across is an explicit conversion, i.e. `Celsius::from(Fahrenheit(212.0))`
+impl From<Fahrenheit> for Celsius {
+    fn from(f: Fahrenheit) -> Celsius {
+        Celsius(fahrenheit_to_celsius(f.0))
+    }
+}
+
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Fahrenheit {
+        Fahrenheit(celsius_to_fahrenheit(c.0))
+    }
+}
+
+// celsius is the base for temperatures, so it passes straight through
+impl Convertible for Celsius {
+    fn to_base(&self) -> f64 {
+        self.0
+    }
+
+    fn from_base(base: f64) -> Celsius {
+        Celsius(base)
+    }
+}
+
+impl Convertible for Fahrenheit {
+    fn to_base(&self) -> f64 {
+        fahrenheit_to_celsius(self.0)
+    }
+
+    fn from_base(base: f64) -> Fahrenheit {
+        Fahrenheit(celsius_to_fahrenheit(base))
+    }
+}
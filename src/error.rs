@@ -0,0 +1,92 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseFloatError;
+
+// errors that can come up while reading or converting a temperature
+#[derive(Debug, PartialEq)]
+pub enum TempError {
+    ParseFailed(String),
+    NotFinite,
+    DivisionByZero,
+    AmbiguousSeparator(String),
+    BelowAbsoluteZero,
+    AboveMaximum(f64),
+    UnknownUnit(String),
+    MissingUnit(String),
+    HumidityOutOfRange(f64),
+    NegativeMass(f64),
+    InvalidStep(f64),
+    InvalidConfig(String),
+    Line(usize, Box<TempError>),
+    Io(String),
+}
+
+impl fmt::Display for TempError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TempError::ParseFailed(reason) => write!(f, "not a valid temperature: {reason}"),
+            TempError::NotFinite => write!(f, "temperatures have to be a finite number"),
+            TempError::DivisionByZero => write!(f, "a fraction can't have a denominator of 0"),
+            TempError::AmbiguousSeparator(input) => {
+                write!(f, "'{input}' uses both '.' and ',' so the decimal point is ambiguous")
+            }
+            TempError::BelowAbsoluteZero => write!(f, "that temperature is below absolute zero"),
+            TempError::AboveMaximum(max) => {
+                write!(f, "that temperature is above {max}K, which is too hot to be a real reading")
+            }
+            TempError::UnknownUnit(unit) => {
+                write!(f, "'{unit}' is not a known unit, use celsius, fahrenheit, kelvin, rankine, newton, or reaumur")
+            }
+            TempError::MissingUnit(input) => {
+                write!(f, "'{input}' needs a unit on the end, i.e. {input}F or {input}C")
+            }
+            TempError::HumidityOutOfRange(humidity) => {
+                write!(f, "relative humidity has to be between 0 and 100, got {humidity}")
+            }
+            TempError::NegativeMass(mass) => write!(f, "a mass can't be negative, got {mass}"),
+            TempError::InvalidStep(step) => write!(f, "the table step has to be above 0, got {step}"),
+            TempError::InvalidConfig(reason) => write!(f, "bad config: {reason}"),
+            TempError::Line(line, err) => write!(f, "line {line}: {err}"),
+            TempError::Io(reason) => write!(f, "could not read input: {reason}"),
+        }
+    }
+}
+
+impl Error for TempError {}
+
+// the process exit code for an error, so scripts can tell what went wrong:
+// 2 when the input couldn't be read as a temperature, 3 when it was outside the allowed range,
+// and 1 for anything else like an io failure
+pub fn error_exit_code(e: &TempError) -> u8 {
+    match e {
+        TempError::ParseFailed(_)
+        | TempError::NotFinite
+        | TempError::DivisionByZero
+        | TempError::AmbiguousSeparator(_)
+        | TempError::UnknownUnit(_)
+        | TempError::MissingUnit(_)
+        | TempError::InvalidConfig(_) => 2,
+        TempError::BelowAbsoluteZero
+        | TempError::AboveMaximum(_)
+        | TempError::HumidityOutOfRange(_)
+        | TempError::NegativeMass(_)
+        | TempError::InvalidStep(_) => 3,
+        TempError::Line(_, err) => error_exit_code(err),
+        TempError::Io(_) => 1,
+    }
+}
+
+// lets `?` be used straight on a `str::parse::<f64>()` result
+impl From<ParseFloatError> for TempError {
+    fn from(err: ParseFloatError) -> Self {
+        TempError::ParseFailed(err.to_string())
+    }
+}
+
+// io::Error isn't PartialEq, so only its message is kept
+impl From<io::Error> for TempError {
+    fn from(err: io::Error) -> Self {
+        TempError::Io(err.to_string())
+    }
+}
@@ -0,0 +1,205 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::error::TempError;
+use crate::stats::temp_stats;
+use crate::table::results_table;
+use crate::temperature::{
+    check_range, convert, format_result, parse_temperature, Unit, DEFAULT_PRECISION,
+};
+
+// how many lines convert_file_with_progress reads between progress messages
+pub const PROGRESS_INTERVAL: usize = 10_000;
+
+// reads a file with one temperature per line and converts every value
+pub fn convert_file(path: &Path, from: Unit, to: Unit) -> Result<Vec<f64>, TempError> {
+    let file = File::open(path)?;
+    convert_reader(BufReader::new(file), from, to)
+}
+
+// convert_file for big files, writing "processed N" to progress every PROGRESS_INTERVAL lines
+// progress is usually stderr, but it can be anything so the messages can be checked
+// once cancel is set (main sets it on Ctrl-C) reading stops after the current line and
+// whatever was converted so far is returned
+// with max_kelvin set (strict mode) a value hotter than that is an error, see check_range
+pub fn convert_file_with_progress<W: Write>(
+    path: &Path,
+    from: Unit,
+    to: Unit,
+    max_kelvin: Option<f64>,
+    mut progress: W,
+    cancel: &AtomicBool,
+) -> Result<Vec<f64>, TempError> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let values = read_values_with_progress(reader, from, max_kelvin, &mut progress, cancel)?;
+    Ok(values.into_iter().map(|value| convert(value, from, to)).collect())
+}
+
+// does the actual work for convert_file so it can be fed from anything readable
+pub fn convert_reader<R: BufRead>(reader: R, from: Unit, to: Unit) -> Result<Vec<f64>, TempError> {
+    let values = read_values(reader, from)?;
+    Ok(values.into_iter().map(|value| convert(value, from, to)).collect())
+}
+
+// converts a file like convert_file, but returns CSV text with an `input,result` header
+// results are written with `precision` decimals, see format_result
+// max_kelvin works the same as in convert_file_with_progress
+pub fn convert_file_to_csv(
+    path: &Path,
+    from: Unit,
+    to: Unit,
+    precision: u32,
+    max_kelvin: Option<f64>,
+) -> Result<String, TempError> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let never = AtomicBool::new(false);
+    let values = read_values_with_progress(reader, from, max_kelvin, &mut io::sink(), &never)?;
+
+    let mut csv = String::from("input,result\n");
+    for value in values {
+        let result = format_result(convert(value, from, to), precision);
+        csv.push_str(&format!("{value},{result}\n"));
+    }
+
+    Ok(csv)
+}
+
+// the line printed after a file's results by `file --summary`, i.e.
+// "summary: mean 50.00, min 0.00, max 100.00", None when there were no results
+pub fn summary_line(results: &[f64]) -> Option<String> {
+    let stats = temp_stats(results)?;
+    let [mean, min, max] =
+        [stats.mean, stats.min, stats.max].map(|v| format_result(v, DEFAULT_PRECISION));
+    Some(format!("summary: mean {mean}, min {min}, max {max}"))
+}
+
+// parses and validates every temperature before anything gets converted
+// blank lines are skipped, and errors carry the 1-based line number they came from
+fn read_values<R: BufRead>(reader: R, from: Unit) -> Result<Vec<f64>, TempError> {
+    read_values_with_progress(reader, from, None, &mut io::sink(), &AtomicBool::new(false))
+}
+
+fn read_values_with_progress<R: BufRead, W: Write>(
+    reader: R,
+    from: Unit,
+    max_kelvin: Option<f64>,
+    progress: &mut W,
+    cancel: &AtomicBool,
+) -> Result<Vec<f64>, TempError> {
+    let mut values = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        // counts every line read, blank ones included, so the numbers match the file
+        if (index + 1) % PROGRESS_INTERVAL == 0 {
+            writeln!(progress, "processed {}", index + 1)?;
+        }
+        if !line.trim().is_empty() {
+            let value = parse_temperature(&line)
+                .and_then(|value| check_range(value, from, max_kelvin))
+                .map_err(|err| TempError::Line(index + 1, Box::new(err)))?;
+            values.push(value);
+        }
+
+        // checked after the line so the one being read when Ctrl-C came in still gets finished
+        if cancel.load(Ordering::Relaxed) {
+            break;
+        }
+    }
+
+    Ok(values)
+}
+
+// converts every line of piped input, writing one result per line to output with
+// DEFAULT_PRECISION decimals
+// a bad line is reported to errors with its line number and skipped, blank lines are ignored
+// once every line is done the first bad one comes back as the error, so the exit code shows
+// that something was skipped
+pub fn convert_lines<R: BufRead, W: Write, E: Write>(
+    input: R,
+    output: W,
+    errors: E,
+    from: Unit,
+    to: Unit,
+) -> Result<(), TempError> {
+    convert_lines_with(input, output, errors, from, to, None)
+}
+
+// like convert_lines, but with `max_kelvin` set (strict mode) values hotter than that are
+// reported as bad lines too, see check_range
+pub fn convert_lines_with<R: BufRead, W: Write, E: Write>(
+    input: R,
+    mut output: W,
+    errors: E,
+    from: Unit,
+    to: Unit,
+    max_kelvin: Option<f64>,
+) -> Result<(), TempError> {
+    for_each_value(input, errors, from, max_kelvin, |value| {
+        writeln!(output, "{}", format_result(convert(value, from, to), DEFAULT_PRECISION))
+    })
+}
+
+// like convert_lines, but the results are held until input closes and then written as one
+// table with Input and Result columns as wide as their widest value, see results_table
+// max_kelvin and the error for bad lines work the same as in convert_lines_with, the table
+// of the good lines is still written when there were bad ones
+pub fn convert_lines_table<R: BufRead, W: Write, E: Write>(
+    input: R,
+    mut output: W,
+    errors: E,
+    from: Unit,
+    to: Unit,
+    max_kelvin: Option<f64>,
+) -> Result<(), TempError> {
+    let mut results = Vec::new();
+    let outcome = for_each_value(input, errors, from, max_kelvin, |value| {
+        results.push((value, convert(value, from, to)));
+        Ok(())
+    });
+
+    write!(output, "{}", results_table(&results, from, to))?;
+    outcome
+}
+
+// hands every valid value of the input to `f`, bad lines go to errors with their line number
+// and the first of them is returned as a TempError::Line after the last line
+fn for_each_value<R: BufRead, E: Write, F: FnMut(f64) -> io::Result<()>>(
+    mut input: R,
+    mut errors: E,
+    from: Unit,
+    max_kelvin: Option<f64>,
+    mut f: F,
+) -> Result<(), TempError> {
+    // one buffer is reused for every line, so long piped input doesn't allocate per line
+    let mut line = String::new();
+    let mut number = 0;
+    let mut first_error = None;
+
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match parse_temperature(&line).and_then(|value| check_range(value, from, max_kelvin)) {
+            Ok(value) => f(value)?,
+            Err(err) => {
+                writeln!(errors, "line {number}: {err}")?;
+                first_error.get_or_insert(TempError::Line(number, Box::new(err)));
+            }
+        }
+    }
+
+    match first_error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
@@ -1,8 +1,11 @@
-use std::io;
 use rand::Rng;
 use std::cmp::Ordering;
+use crate::error::TempError;
+use crate::input::read_number;
 
-pub fn guess() {
+// the guessing game from chapter 2 of the book, keeps asking until the number is found
+// anything that isn't a number just asks again, only closing input ends the game early
+pub fn guessing_game() -> Result<(), TempError> {
     println!("Guess the number!");
 
     let secret_number = rand::thread_rng().gen_range(1..=100);
@@ -10,28 +13,26 @@ pub fn guess() {
     //println!("The secret number is: {secret_number}");
 
     loop {
-        println!("Please input your guess.");
-
-        let mut guess = String::new();
-
-        io::stdin()
-            .read_line(&mut guess)
-            .expect("Failed to read line");
-
-        let guess: u32 = match guess.trim().parse() {
+        let guess: u32 = match read_number("Please input your guess (1-100).") {
             Ok(num) => num,
-            Err(_) => continue,
+            Err(TempError::ParseFailed(_)) => continue,
+            Err(err) => return Err(err),
         };
 
         println!("You guessed: {guess}");
 
-        match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
+        match compare_guess(guess, secret_number) {
+            Ordering::Less => println!("too low"),
+            Ordering::Greater => println!("too high"),
             Ordering::Equal => {
-                println!("You win!");
-                break;
+                println!("correct");
+                return Ok(());
             }
         }
     }
-}
\ No newline at end of file
+}
+
+// how the guess compares to the secret, Less means the guess was too low
+pub fn compare_guess(guess: u32, secret: u32) -> Ordering {
+    guess.cmp(&secret)
+}
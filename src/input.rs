@@ -0,0 +1,53 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::str::FromStr;
+use crate::error::TempError;
+
+// true when a person is typing at stdin, false when input is piped or redirected from a file
+pub fn is_interactive() -> bool {
+    io::stdin().is_terminal()
+}
+
+// prints the prompt and reads a single line from input
+// returns None once input is closed so callers don't loop forever
+pub fn read_input<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    prompt: &str,
+) -> io::Result<Option<String>> {
+    let mut line = String::new();
+
+    if read_input_into(input, output, prompt, &mut line)? {
+        Ok(Some(line))
+    } else {
+        Ok(None)
+    }
+}
+
+// like read_input, but reads into a buffer the caller keeps around, so a loop can reuse
+// one String instead of allocating a new one every line
+// the buffer is cleared first, and false means input is closed
+pub fn read_input_into<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    prompt: &str,
+    line: &mut String,
+) -> io::Result<bool> {
+    writeln!(output, "{prompt}")?;
+
+    line.clear();
+    Ok(input.read_line(line)? != 0)
+}
+
+// trims the surrounding whitespace (including the newline from read_line) and parses it
+pub fn parse_trimmed<T: FromStr>(s: &str) -> Option<T> {
+    s.trim().parse().ok()
+}
+
+// prints the prompt to stdout and parses the next line of stdin into whatever type is asked for
+pub fn read_number<T: FromStr>(prompt: &str) -> Result<T, TempError> {
+    let stdin = io::stdin();
+    let line = read_input(&mut stdin.lock(), &mut io::stdout(), prompt)?
+        .ok_or_else(|| TempError::Io(String::from("input was closed")))?;
+
+    parse_trimmed(&line).ok_or_else(|| TempError::ParseFailed(format!("'{}'", line.trim())))
+}
@@ -0,0 +1,84 @@
+use std::str::FromStr;
+use crate::convertible::{convert_within, UnitKind};
+use crate::error::TempError;
+use crate::input::read_number;
+
+// the units a length can be given in, laid out like temperature::Unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LengthUnit {
+    Meters,
+    Feet,
+    Inches,
+    Kilometers,
+    Miles,
+}
+
+impl LengthUnit {
+    // the unit's full name in lowercase, i.e. "meters"
+    pub fn name(self) -> &'static str {
+        match self {
+            LengthUnit::Meters => "meters",
+            LengthUnit::Feet => "feet",
+            LengthUnit::Inches => "inches",
+            LengthUnit::Kilometers => "kilometers",
+            LengthUnit::Miles => "miles",
+        }
+    }
+
+    // how many meters one of this unit is, the foot and inch are exact by definition
+    pub fn meters_per_unit(self) -> f64 {
+        match self {
+            LengthUnit::Meters => 1.0,
+            LengthUnit::Feet => 0.3048,
+            LengthUnit::Inches => 0.0254,
+            LengthUnit::Kilometers => 1000.0,
+            LengthUnit::Miles => 1609.344,
+        }
+    }
+}
+
+// accepts the unit's short form or full name in any case, i.e. "ft", "foot", or "Feet"
+impl FromStr for LengthUnit {
+    type Err = TempError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "m" | "meter" | "meters" | "metre" | "metres" => Ok(LengthUnit::Meters),
+            "ft" | "foot" | "feet" => Ok(LengthUnit::Feet),
+            "in" | "inch" | "inches" => Ok(LengthUnit::Inches),
+            "km" | "kilometer" | "kilometers" | "kilometre" | "kilometres" => Ok(LengthUnit::Kilometers),
+            "mi" | "mile" | "miles" => Ok(LengthUnit::Miles),
+            _ => Err(TempError::UnknownUnit(s.trim().to_string())),
+        }
+    }
+}
+
+pub fn to_meters(value: f64, unit: LengthUnit) -> f64 {
+    value * unit.meters_per_unit()
+}
+
+pub fn from_meters(meters: f64, unit: LengthUnit) -> f64 {
+    meters / unit.meters_per_unit()
+}
+
+// meters are the base, with no offset since every length starts at 0
+impl UnitKind for LengthUnit {
+    fn to_base_factor(self) -> f64 {
+        self.meters_per_unit()
+    }
+}
+
+// converts a bare length from one unit to another by going through meters
+pub fn convert_length(value: f64, from: LengthUnit, to: LengthUnit) -> f64 {
+    convert_within(value, from, to)
+}
+
+// asks for a length and the two units on stdin, then prints the converted length
+pub fn length_convert() -> Result<(), TempError> {
+    let value: f64 = read_number("Input a length to convert")?;
+    let from: LengthUnit = read_number("Convert from which unit? (m, ft, in, km, mi)")?;
+    let to: LengthUnit = read_number("Convert to which unit? (m, ft, in, km, mi)")?;
+
+    println!("{value} {} = {} {}", from.name(), convert_length(value, from, to), to.name());
+    Ok(())
+}
@@ -0,0 +1,31 @@
+pub mod app;
+pub mod ch3_practice;
+pub mod cli;
+pub mod commands;
+pub mod common_concepts;
+pub mod config;
+pub mod convertible;
+pub mod degrees;
+pub mod error;
+pub mod file;
+pub mod guess;
+pub mod input;
+pub mod length;
+pub mod mass;
+pub mod ownership;
+pub mod session;
+pub mod slices;
+pub mod stats;
+pub mod table;
+pub mod temperature;
+pub mod weather;
+
+pub use error::TempError;
+pub use file::convert_file;
+pub use temperature::{
+    celsius_to_fahrenheit, celsius_to_kelvin, celsius_to_newton, celsius_to_reaumur,
+    check_absolute_zero, check_kelvin, convert, convert_batch, fahrenheit_to_celsius,
+    fahrenheit_to_rankine, kelvin_to_celsius, kelvin_to_rankine, newton_to_celsius,
+    parse_temperature, rankine_to_fahrenheit, rankine_to_kelvin, reaumur_to_celsius, round_to,
+    round_with, RoundMode, Temperature, Unit,
+};
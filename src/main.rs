@@ -1,7 +1,34 @@
-//mod guess;
-mod ch3_practice;
+use std::env;
+use std::process::ExitCode;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use learning_rust::app::{self, AppError, Command};
+use signal_hook::consts::SIGINT;
+use signal_hook::flag;
 
-fn main() {
-    //guess::guess();
-    ch3_practice::temp_convert();
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    // during a file conversion Ctrl-C only sets this flag, so the current line gets finished
+    // and what was converted is still printed, a second Ctrl-C quits straight away
+    // everything else keeps the normal Ctrl-C, otherwise the interactive prompt couldn't be left
+    let cancel = Arc::new(AtomicBool::new(false));
+    if let Ok(Command::File(..)) = app::route(&args) {
+        let registered = flag::register_conditional_shutdown(SIGINT, 130, Arc::clone(&cancel))
+            .and_then(|_| flag::register(SIGINT, Arc::clone(&cancel)));
+        if let Err(err) = registered {
+            eprintln!("warning: could not listen for Ctrl-C: {err}");
+        }
+    }
+
+    match app::dispatch_with(&args, &cancel) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            if let AppError::UnknownCommand(_) = err {
+                eprintln!("{}", app::USAGE);
+            }
+            ExitCode::from(err.exit_code())
+        }
+    }
 }
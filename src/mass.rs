@@ -0,0 +1,78 @@
+use std::str::FromStr;
+use crate::convertible::{convert_within, UnitKind};
+use crate::error::TempError;
+use crate::temperature::{format_result, DEFAULT_PRECISION};
+
+// the units a mass can be given in, laid out like length::LengthUnit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MassUnit {
+    Grams,
+    Kilograms,
+    Pounds,
+    Ounces,
+}
+
+impl MassUnit {
+    // the unit's full name in lowercase, i.e. "grams"
+    pub fn name(self) -> &'static str {
+        match self {
+            MassUnit::Grams => "grams",
+            MassUnit::Kilograms => "kilograms",
+            MassUnit::Pounds => "pounds",
+            MassUnit::Ounces => "ounces",
+        }
+    }
+
+    // how many grams one of this unit is, the pound is exactly 453.59237 g by definition
+    // and an ounce is a sixteenth of that
+    pub fn grams_per_unit(self) -> f64 {
+        match self {
+            MassUnit::Grams => 1.0,
+            MassUnit::Kilograms => 1000.0,
+            MassUnit::Pounds => 453.59237,
+            MassUnit::Ounces => 453.59237 / 16.0,
+        }
+    }
+}
+
+// accepts the unit's short form or full name in any case, i.e. "lb", "pound", or "Pounds"
+impl FromStr for MassUnit {
+    type Err = TempError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "g" | "gram" | "grams" => Ok(MassUnit::Grams),
+            "kg" | "kilogram" | "kilograms" => Ok(MassUnit::Kilograms),
+            "lb" | "lbs" | "pound" | "pounds" => Ok(MassUnit::Pounds),
+            "oz" | "ounce" | "ounces" => Ok(MassUnit::Ounces),
+            _ => Err(TempError::UnknownUnit(s.trim().to_string())),
+        }
+    }
+}
+
+// grams are the base, with no offset since every mass starts at 0
+impl UnitKind for MassUnit {
+    fn to_base_factor(self) -> f64 {
+        self.grams_per_unit()
+    }
+}
+
+// converts a mass from one unit to another by going through grams
+// unlike temperatures there's no offset, so the only impossible values are negative ones,
+// and like temperatures inf and NaN aren't masses at all
+pub fn convert_mass(value: f64, from: MassUnit, to: MassUnit) -> Result<f64, TempError> {
+    if !value.is_finite() {
+        return Err(TempError::NotFinite);
+    }
+    if value < 0.0 {
+        return Err(TempError::NegativeMass(value));
+    }
+
+    Ok(convert_within(value, from, to))
+}
+
+// the line `mass` prints for a finished conversion, i.e. "1 pounds = 453.59 grams"
+pub fn mass_line(value: f64, from: MassUnit, to: MassUnit, result: f64) -> String {
+    let result = format_result(result, DEFAULT_PRECISION);
+    format!("{value} {} = {result} {}", from.name(), to.name())
+}
@@ -0,0 +1,79 @@
+use std::io::{self, Write};
+
+// a walk through chapter 4's ownership rules, printing what happens at each step
+// the runnable version of examples/ownership.rs, see there for the longer notes
+pub fn ownership_demo() {
+    write_ownership_demo(&mut io::stdout()).expect("Failed to write to stdout");
+}
+
+// does the work for ownership_demo, writing to anything so the output can be tested
+// the cases that wouldn't compile are described instead of run
+pub fn write_ownership_demo<W: Write>(out: &mut W) -> io::Result<()> {
+    writeln!(out, "== move ==")?;
+    let s1 = String::from("hello");
+    writeln!(out, "s1 owns the String \"{s1}\"")?;
+    let s2 = s1;
+    writeln!(out, "let s2 = s1; the String moved into s2, which now holds \"{s2}\"")?;
+    writeln!(out, "using s1 after this would be a compile error: borrow of moved value: `s1`")?;
+
+    writeln!(out, "\n== clone ==")?;
+    let s1 = String::from("hello");
+    let s2 = s1.clone();
+    writeln!(out, "let s2 = s1.clone(); the heap data was cloned, so both are usable")?;
+    writeln!(out, "s1 = \"{s1}\", s2 = \"{s2}\"")?;
+
+    writeln!(out, "\n== copy ==")?;
+    let x = 5;
+    let y = x;
+    writeln!(out, "let y = x; i32 is Copy, so x was copied instead of moved")?;
+    writeln!(out, "x = {x}, y = {y}")?;
+
+    writeln!(out, "\n== references ==")?;
+    let s = String::from("hello");
+    let len = calculate_length(&s);
+    writeln!(out, "calculate_length(&s) borrowed s and gave back {len}, s still owns \"{s}\"")?;
+    let mut s = s;
+    append_world(&mut s);
+    writeln!(out, "append_world(&mut s) changed s through a mutable borrow, s is now \"{s}\"")?;
+    writeln!(out, "taking two &mut s at once would be a compile error: cannot borrow `s` as mutable more than once")?;
+
+    writeln!(out, "\n== slices ==")?;
+    let s = String::from("hello world");
+    let hello = &s[0..5];
+    let world = &s[6..11];
+    writeln!(out, "&s[0..5] is \"{hello}\" and &s[6..11] is \"{world}\", both borrow part of s")?;
+    writeln!(out, "calling s.clear() while a slice is still in use would be a compile error")?;
+
+    Ok(())
+}
+
+/// takes ownership of `s`, so it has to hand the String back alongside its length or the
+/// caller would lose it, i.e. `let (s, len) = calculate_length_moved(s);`
+pub fn calculate_length_moved(s: String) -> (String, usize) {
+    let length = s.len();
+    (s, length)
+}
+
+/// only borrows `s`, so the caller keeps ownership and nothing needs to be returned but
+/// the length, i.e. `let len = calculate_length(&s);`
+pub fn calculate_length(s: &str) -> usize {
+    s.len()
+}
+
+/// changes the caller's String in place through a mutable borrow, i.e.
+/// `append_world(&mut s);` turns "hello" into "hello, world"
+///
+/// only one mutable borrow can be alive at a time, so this wouldn't compile:
+///
+/// ```compile_fail
+/// use learning_rust::ownership::append_world;
+///
+/// let mut s = String::from("hello");
+/// let r1 = &mut s;
+/// let r2 = &mut s; // error: cannot borrow `s` as mutable more than once at a time
+/// append_world(r1);
+/// append_world(r2);
+/// ```
+pub fn append_world(s: &mut String) {
+    s.push_str(", world");
+}
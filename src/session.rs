@@ -0,0 +1,92 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::temperature::Unit;
+
+pub const DEFAULT_HISTORY_SIZE: usize = 100;
+
+// one finished conversion from the interactive loop
+// precision is how many decimals the result was rounded to, and is printed with
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvertRecord {
+    pub input: f64,
+    pub from: Unit,
+    pub to: Unit,
+    pub result: f64,
+    pub precision: u32,
+}
+
+// i.e. "98.6°F -> 37.00°C", the input is shown as it was typed
+impl fmt::Display for ConvertRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = self.precision as usize;
+        let result = self.to.temperature(self.result);
+        write!(f, "{} -> {result:.precision$}", self.from.temperature(self.input))
+    }
+}
+
+// keeps the conversions made during an interactive session, oldest first
+// only the last `capacity` records are kept so a long session can't grow forever
+// max_kelvin turns on strict mode for every conversion in the session, see check_range
+pub struct Session {
+    history: Vec<ConvertRecord>,
+    capacity: usize,
+    max_kelvin: Option<f64>,
+}
+
+impl Session {
+    pub fn new(capacity: usize) -> Session {
+        Session {
+            history: Vec::new(),
+            capacity,
+            max_kelvin: None,
+        }
+    }
+
+    // the same session, but rejecting anything hotter than max_kelvin
+    pub fn strict(self, max_kelvin: f64) -> Session {
+        Session {
+            max_kelvin: Some(max_kelvin),
+            ..self
+        }
+    }
+
+    pub fn max_kelvin(&self) -> Option<f64> {
+        self.max_kelvin
+    }
+
+    pub fn record(&mut self, record: ConvertRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.history.len() == self.capacity {
+            self.history.remove(0);
+        }
+        self.history.push(record);
+    }
+
+    pub fn history(&self) -> &[ConvertRecord] {
+        &self.history
+    }
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Session::new(DEFAULT_HISTORY_SIZE)
+    }
+}
+
+// appends the record to a log file as "<unix seconds> <record>",
+// i.e. "1700000000 98.6°F -> 37.00°C"
+// the file is created if it doesn't exist yet, and earlier lines are never touched
+pub fn log_conversion(path: &Path, record: &ConvertRecord) -> io::Result<()> {
+    // a clock set before 1970 is the only way this fails, 0 is close enough for a log
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{timestamp} {record}")
+}
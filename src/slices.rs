@@ -0,0 +1,16 @@
+// the first_word example from chapter 4's slices section, returning part of `s` instead
+// of copying it, so the word stays tied to the string it came from
+// leading whitespace is skipped, and a string with no words gives back ""
+pub fn first_word(s: &str) -> &str {
+    let s = s.trim_start();
+
+    // the book walks the bytes looking for b' ', char_indices does the same but also
+    // catches tabs, newlines, and other unicode whitespace
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() {
+            return &s[..i];
+        }
+    }
+
+    s
+}
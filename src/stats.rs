@@ -0,0 +1,53 @@
+// the mean of a batch of temperatures, None for an empty slice so there's no divide by zero
+pub fn average(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let sum: f64 = values.iter().sum();
+    Some(sum / values.len() as f64)
+}
+
+// summary numbers for a batch of temperatures
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub range: f64,
+}
+
+// works out min, max, and mean in a single pass, None for an empty slice
+// callers must pass finite values, a NaN would make min and max depend on where it shows up
+// (parse_temperature already rejects NaN and infinity)
+pub fn temp_stats(values: &[f64]) -> Option<TempStats> {
+    let (&first, rest) = values.split_first()?;
+
+    let mut min = first;
+    let mut max = first;
+    let mut sum = first;
+
+    for &value in rest {
+        min = min.min(value);
+        max = max.max(value);
+        sum += value;
+    }
+
+    Some(TempStats {
+        min,
+        max,
+        mean: sum / values.len() as f64,
+        range: max - min,
+    })
+}
+
+// the positions of every reading strictly hotter than threshold, i.e. for alerting on hot
+// readings, a reading equal to the threshold isn't counted
+pub fn indices_above(values: &[f64], threshold: f64) -> Vec<usize> {
+    values
+        .iter()
+        .enumerate()
+        .filter(|&(_, &value)| value > threshold)
+        .map(|(index, _)| index)
+        .collect()
+}
@@ -0,0 +1,118 @@
+use crate::error::TempError;
+use crate::temperature::{
+    convert, convert_all, format_result, round_nice, round_to, Unit, DEFAULT_PRECISION,
+};
+
+// lines two columns of text up under their headers, right aligned and padded to the widest cell
+fn format_columns(headers: (&str, &str), rows: &[(String, String)]) -> String {
+    let left = rows.iter().map(|row| row.0.chars().count()).fold(headers.0.len(), usize::max);
+    let right = rows.iter().map(|row| row.1.chars().count()).fold(headers.1.len(), usize::max);
+
+    let mut table = format!("{:>left$} | {:>right$}\n", headers.0, headers.1);
+    table.push_str(&format!("{}-+-{}\n", "-".repeat(left), "-".repeat(right)));
+
+    for (input, result) in rows {
+        table.push_str(&format!("{input:>left$} | {result:>right$}\n"));
+    }
+
+    table
+}
+
+// a conversion table for every value from start to end (inclusive) going up by step
+// the step has to be positive, otherwise the table would never reach the end
+pub fn print_table(
+    start: f64,
+    end: f64,
+    step: f64,
+    from: Unit,
+    to: Unit,
+) -> Result<String, TempError> {
+    build_table(start, end, step, from, to, |result| format_result(result, DEFAULT_PRECISION))
+}
+
+// print_table's "pretty" mode, with the results rounded to the nearest half degree so the
+// table is easier to skim, i.e. 98.6°F reads as 37.0 instead of 37.00
+pub fn print_table_pretty(
+    start: f64,
+    end: f64,
+    step: f64,
+    from: Unit,
+    to: Unit,
+) -> Result<String, TempError> {
+    build_table(start, end, step, from, to, |result| format_result(round_nice(result), 1))
+}
+
+// does the work for both table modes, `show` is how each result gets written
+fn build_table<F: Fn(f64) -> String>(
+    start: f64,
+    end: f64,
+    step: f64,
+    from: Unit,
+    to: Unit,
+    show: F,
+) -> Result<String, TempError> {
+    if step <= 0.0 || !step.is_finite() {
+        return Err(TempError::InvalidStep(step));
+    }
+
+    // each value is worked out from its row number so the float error doesn't pile up
+    let mut rows = Vec::new();
+    let mut row = 0;
+    loop {
+        let value = start + step * row as f64;
+        if value > end + step * 1e-9 {
+            break;
+        }
+
+        let result = show(convert(value, from, to));
+        rows.push((round_to(value, DEFAULT_PRECISION).to_string(), result));
+        row += 1;
+    }
+
+    let headers = (capitalize(from.name()), capitalize(to.name()));
+    Ok(format_columns((&headers.0, &headers.1), &rows))
+}
+
+// one value shown in every unit, i.e. for `showall 0 c`
+//
+//       Unit | Temperature
+//    --------+------------
+//    Celsius |      0.00°C
+//    ...
+pub fn print_all(value: f64, from: Unit) -> String {
+    let rows: Vec<(String, String)> = convert_all(value, from)
+        .into_iter()
+        .map(|(unit, result)| {
+            let result = unit.temperature(result);
+            (capitalize(unit.name()), format!("{:.*}", DEFAULT_PRECISION as usize, result))
+        })
+        .collect();
+
+    format_columns(("Unit", "Temperature"), &rows)
+}
+
+// the (input, result) pairs from `convert --table`, i.e.
+//
+//     Input |   Result
+//    -------+---------
+//     -40°F | -40.00°C
+//    98.6°F |  37.00°C
+pub fn results_table(results: &[(f64, f64)], from: Unit, to: Unit) -> String {
+    let rows: Vec<(String, String)> = results
+        .iter()
+        .map(|&(value, result)| {
+            let result = format!("{:.*}", DEFAULT_PRECISION as usize, to.temperature(result));
+            (from.temperature(value).to_string(), result)
+        })
+        .collect();
+
+    format_columns(("Input", "Result"), &rows)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,921 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use crate::convertible::UnitKind;
+use crate::error::TempError;
+use crate::input::parse_trimmed;
+
+// a temperature value tagged with the unit it was measured in
+pub enum Temperature {
+    Celsius(f64),
+    Fahrenheit(f64),
+    Kelvin(f64),
+    Rankine(f64),
+    Newton(f64),
+    Reaumur(f64),
+}
+
+// the units a temperature can be given in, without a value attached
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Rankine,
+    Newton,
+    Reaumur,
+}
+
+/// converts fahrenheit to celsius
+///
+/// ```
+/// use learning_rust::temperature::fahrenheit_to_celsius;
+///
+/// assert!((fahrenheit_to_celsius(212.0) - 100.0).abs() < 1e-9);
+/// ```
+pub fn fahrenheit_to_celsius(degf: f64) -> f64 {
+    (degf - 32.0) * 5.0 / 9.0
+}
+
+/// converts celsius to fahrenheit
+///
+/// ```
+/// use learning_rust::temperature::celsius_to_fahrenheit;
+///
+/// assert!((celsius_to_fahrenheit(100.0) - 212.0).abs() < 1e-9);
+/// ```
+pub fn celsius_to_fahrenheit(degc: f64) -> f64 {
+    degc * 9.0 / 5.0 + 32.0
+}
+
+/// converts celsius to kelvin
+///
+/// ```
+/// use learning_rust::temperature::celsius_to_kelvin;
+///
+/// assert!((celsius_to_kelvin(0.0) - 273.15).abs() < 1e-9);
+/// ```
+pub fn celsius_to_kelvin(c: f64) -> f64 {
+    c + 273.15
+}
+
+/// converts kelvin to celsius
+///
+/// ```
+/// use learning_rust::temperature::kelvin_to_celsius;
+///
+/// assert!((kelvin_to_celsius(373.15) - 100.0).abs() < 1e-9);
+/// ```
+pub fn kelvin_to_celsius(k: f64) -> f64 {
+    k - 273.15
+}
+
+/// converts fahrenheit to rankine
+///
+/// ```
+/// use learning_rust::temperature::fahrenheit_to_rankine;
+///
+/// assert!((fahrenheit_to_rankine(32.0) - 491.67).abs() < 1e-9);
+/// ```
+pub fn fahrenheit_to_rankine(f: f64) -> f64 {
+    f + 459.67
+}
+
+/// converts rankine to fahrenheit
+///
+/// ```
+/// use learning_rust::temperature::rankine_to_fahrenheit;
+///
+/// assert!(rankine_to_fahrenheit(0.0) == -459.67);
+/// ```
+pub fn rankine_to_fahrenheit(r: f64) -> f64 {
+    r - 459.67
+}
+
+/// rankine is to fahrenheit what kelvin is to celsius, so the two only differ by the 9/5 scale
+///
+/// ```
+/// use learning_rust::temperature::kelvin_to_rankine;
+///
+/// assert!((kelvin_to_rankine(273.15) - 491.67).abs() < 1e-9);
+/// ```
+pub fn kelvin_to_rankine(k: f64) -> f64 {
+    k * 9.0 / 5.0
+}
+
+/// converts rankine to kelvin
+///
+/// ```
+/// use learning_rust::temperature::rankine_to_kelvin;
+///
+/// assert!((rankine_to_kelvin(491.67) - 273.15).abs() < 1e-9);
+/// ```
+pub fn rankine_to_kelvin(r: f64) -> f64 {
+    r * 5.0 / 9.0
+}
+
+/// newton and réaumur are old scales that share celsius's zero and only stretch the degree,
+/// water boils at 33°N and 80°Ré
+///
+/// ```
+/// use learning_rust::temperature::celsius_to_newton;
+///
+/// assert!((celsius_to_newton(100.0) - 33.0).abs() < 1e-9);
+/// ```
+pub fn celsius_to_newton(c: f64) -> f64 {
+    c * 33.0 / 100.0
+}
+
+/// converts newton to celsius
+///
+/// ```
+/// use learning_rust::temperature::newton_to_celsius;
+///
+/// assert!((newton_to_celsius(33.0) - 100.0).abs() < 1e-9);
+/// ```
+pub fn newton_to_celsius(n: f64) -> f64 {
+    n * 100.0 / 33.0
+}
+
+/// converts celsius to réaumur
+///
+/// ```
+/// use learning_rust::temperature::celsius_to_reaumur;
+///
+/// assert!((celsius_to_reaumur(100.0) - 80.0).abs() < 1e-9);
+/// ```
+pub fn celsius_to_reaumur(c: f64) -> f64 {
+    c * 4.0 / 5.0
+}
+
+/// converts réaumur to celsius
+///
+/// ```
+/// use learning_rust::temperature::reaumur_to_celsius;
+///
+/// assert!((reaumur_to_celsius(80.0) - 100.0).abs() < 1e-9);
+/// ```
+pub fn reaumur_to_celsius(re: f64) -> f64 {
+    re * 5.0 / 4.0
+}
+
+// absolute zero is -273.15°C / -459.67°F, but converting it to kelvin can land a hair
+// below 0 from float rounding, so that much is let through as 0
+const ABSOLUTE_ZERO_TOLERANCE: f64 = 1e-9;
+
+/// kelvin starts at absolute zero, so anything negative can't physically exist
+///
+/// ```
+/// use learning_rust::temperature::check_kelvin;
+///
+/// assert_eq!(check_kelvin(10.0), Ok(10.0));
+/// assert!(check_kelvin(-1.0).is_err());
+/// ```
+pub fn check_kelvin(k: f64) -> Result<f64, TempError> {
+    if k < -ABSOLUTE_ZERO_TOLERANCE {
+        Err(TempError::BelowAbsoluteZero)
+    } else {
+        Ok(k.max(0.0))
+    }
+}
+
+/// checks a temperature in any unit, returning the value untouched if it's possible
+///
+/// ```
+/// use learning_rust::temperature::{check_absolute_zero, Unit};
+///
+/// assert_eq!(check_absolute_zero(-40.0, Unit::Fahrenheit), Ok(-40.0));
+/// assert!(check_absolute_zero(-300.0, Unit::Celsius).is_err());
+/// ```
+pub fn check_absolute_zero(value: f64, unit: Unit) -> Result<f64, TempError> {
+    check_range(value, unit, None)
+}
+
+/// the surface of the sun, a sensible top end for `--strict` since nothing a person would
+/// be converting gets hotter
+pub const SUN_SURFACE_KELVIN: f64 = 5778.0;
+
+/// reads a strict-mode maximum like "5778", it has to be a number of kelvin above 0
+///
+/// ```
+/// use learning_rust::temperature::parse_max_kelvin;
+///
+/// assert_eq!(parse_max_kelvin("1000"), Some(1000.0));
+/// assert_eq!(parse_max_kelvin("-5"), None);
+/// ```
+pub fn parse_max_kelvin(input: &str) -> Option<f64> {
+    parse_temperature(input).ok().filter(|&max| max > 0.0)
+}
+
+/// the range check every conversion path goes through
+/// absolute zero is always enforced, and with `max_kelvin` set (strict mode) anything hotter
+/// than that is rejected too as a likely data error
+///
+/// ```
+/// use learning_rust::temperature::{check_range, Unit, SUN_SURFACE_KELVIN};
+///
+/// assert_eq!(check_range(10_000.0, Unit::Celsius, None), Ok(10_000.0));
+/// assert!(check_range(10_000.0, Unit::Celsius, Some(SUN_SURFACE_KELVIN)).is_err());
+/// ```
+pub fn check_range(value: f64, unit: Unit, max_kelvin: Option<f64>) -> Result<f64, TempError> {
+    let kelvin = check_kelvin(unit.temperature(value).to_kelvin())?;
+
+    match max_kelvin {
+        Some(max) if kelvin > max => Err(TempError::AboveMaximum(max)),
+        _ => Ok(value),
+    }
+}
+
+/// accepts anything rust's f64 parser does once the surrounding whitespace is trimmed:
+/// - plain numbers with an optional sign, i.e. "98.6", "-40", "+5", ".5"
+/// - exponent notation, i.e. "6.02e1" or "1E-3"
+/// - fractions with an optional whole part, i.e. "3/5" or "98 3/5" (see parse_fraction)
+/// - a degree sign pasted along with the number, i.e. "98.6°" or "37 °C" (the unit letter
+///   is thrown away, use parse_temperature_tagged to keep it)
+/// - a leading en-dash or em-dash instead of a minus sign, i.e. "–40" copied from a document
+///
+/// underscores like "1_000" only work in source code literals, so they're rejected here
+/// "inf" and "nan" parse fine as f64 but aren't real temperatures, so they're rejected too
+///
+/// ```
+/// use learning_rust::temperature::parse_temperature;
+///
+/// assert_eq!(parse_temperature(" 98.6 "), Ok(98.6));
+/// assert_eq!(parse_temperature("98 3/5"), Ok(98.6));
+/// assert_eq!(parse_temperature("37 °C"), Ok(37.0));
+/// assert_eq!(parse_temperature("–40"), Ok(-40.0));
+/// assert!(parse_temperature("inf").is_err());
+/// ```
+pub fn parse_temperature(input: &str) -> Result<f64, TempError> {
+    let input = normalize_minus(strip_degree_sign(input.trim()));
+    let input = input.as_ref();
+
+    let value = if input.contains('/') {
+        parse_fraction(input)?
+    } else {
+        parse_trimmed(input).ok_or_else(|| TempError::ParseFailed(format!("'{input}'")))?
+    };
+
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(TempError::NotFinite)
+    }
+}
+
+// takes a leading or trailing ° off, along with a unit name right after a trailing one,
+// so "37°C", "37°Ré" and "37°Re" all leave "37"
+// letters without the ° are left alone, so "1e5" or "inf" still reach the number parser
+// strip_prefix and trim_end_matches work on whole chars, so the two-byte ° is never split
+fn strip_degree_sign(input: &str) -> &str {
+    let input = input.strip_prefix('°').unwrap_or(input);
+    let rest = input.trim_end_matches(char::is_alphabetic);
+    let unit = &input[rest.len()..];
+    let input = if rest.ends_with('°') && unit.parse::<Unit>().is_ok() { rest } else { input };
+
+    input.strip_suffix('°').unwrap_or(input).trim()
+}
+
+// word processors like to turn "-40" into "–40" (en-dash) or "—40" (em-dash)
+// only a dash at the very start is swapped for '-', one in the middle like "4–0" is left
+// for the number parser to reject, and input without one isn't copied at all
+fn normalize_minus(input: &str) -> Cow<'_, str> {
+    match input.strip_prefix(['\u{2013}', '\u{2014}']) {
+        Some(rest) => Cow::Owned(format!("-{rest}")),
+        None => Cow::Borrowed(input),
+    }
+}
+
+/// parses a temperature with its unit on the end, i.e. "98.6F", "37c", "300 K", or "98.6°F"
+/// any unit name Unit accepts works, and a value without one is an error since there's
+/// nothing to go on
+///
+/// ```
+/// use learning_rust::temperature::{parse_temperature_tagged, Unit};
+///
+/// assert_eq!(parse_temperature_tagged("98.6F"), Ok((98.6, Unit::Fahrenheit)));
+/// assert!(parse_temperature_tagged("100").is_err());
+/// ```
+pub fn parse_temperature_tagged(input: &str) -> Result<(f64, Unit), TempError> {
+    let input = input.trim();
+
+    // the unit is the run of letters at the very end, so "1e3" still has no unit
+    let split = input
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphabetic())
+        .last()
+        .map_or(input.len(), |(index, _)| index);
+    let (value, unit) = input.split_at(split);
+
+    if unit.is_empty() {
+        return Err(TempError::MissingUnit(input.to_string()));
+    }
+
+    Ok((parse_temperature(value)?, unit.parse()?))
+}
+
+/// parse_temperature with optional support for a comma as the decimal separator, i.e. "98,6"
+/// with decimal_comma on, a string using both separators like "1.000,5" is rejected since
+/// it could be a thousands separator or a typo, there's no safe way to tell
+///
+/// ```
+/// use learning_rust::temperature::parse_temperature_locale;
+///
+/// assert_eq!(parse_temperature_locale("98,6", true), Ok(98.6));
+/// assert!(parse_temperature_locale("1.000,5", true).is_err());
+/// ```
+pub fn parse_temperature_locale(input: &str, decimal_comma: bool) -> Result<f64, TempError> {
+    if !decimal_comma {
+        return parse_temperature(input);
+    }
+
+    if input.contains(',') && input.contains('.') {
+        return Err(TempError::AmbiguousSeparator(input.trim().to_string()));
+    }
+
+    parse_temperature(&input.replace(',', "."))
+}
+
+/// parses "numerator/denominator" with an optional whole number in front, i.e. "98 3/5"
+/// the numerator and denominator have to be whole numbers, and a minus sign on the whole
+/// number covers the fraction too, so "-98 3/5" is -98.6, a fraction on its own can have its
+/// own sign, so "-3/5" is -0.6
+///
+/// ```
+/// use learning_rust::temperature::parse_fraction;
+///
+/// assert_eq!(parse_fraction("-98 3/5"), Ok(-98.6));
+/// assert_eq!(parse_fraction("-3/5"), Ok(-0.6));
+/// assert!(parse_fraction("1/0").is_err());
+/// ```
+pub fn parse_fraction(input: &str) -> Result<f64, TempError> {
+    let invalid = || TempError::ParseFailed(format!("'{input}'"));
+
+    let (whole, fraction) = match input.trim().split_once(char::is_whitespace) {
+        Some((whole, fraction)) => (Some(whole), fraction.trim()),
+        None => (None, input.trim()),
+    };
+
+    // the sign is only taken off a fraction on its own, "98 -3/5" stays an error
+    let (negative, fraction) = match (whole, fraction.strip_prefix('-')) {
+        (None, Some(rest)) => (true, rest),
+        _ => (false, fraction),
+    };
+
+    let (numerator, denominator) = fraction.split_once('/').ok_or_else(invalid)?;
+    let numerator: u64 = parse_trimmed(numerator).ok_or_else(invalid)?;
+    let denominator: u64 = parse_trimmed(denominator).ok_or_else(invalid)?;
+
+    if denominator == 0 {
+        return Err(TempError::DivisionByZero);
+    }
+    let fraction = numerator as f64 / denominator as f64;
+
+    match whole {
+        None if negative => Ok(-fraction),
+        None => Ok(fraction),
+        Some(whole) => {
+            let whole: f64 = parse_trimmed(whole).ok_or_else(invalid)?;
+            if whole.fract() != 0.0 {
+                return Err(invalid());
+            }
+
+            if whole.is_sign_negative() {
+                Ok(whole - fraction)
+            } else {
+                Ok(whole + fraction)
+            }
+        }
+    }
+}
+
+// how far apart two temperatures can be, in degrees celsius, and still count as equal
+// 212°F only lands within a few trillionths of 100°C after converting, so == needs some slack
+pub const COMPARE_TOLERANCE: f64 = 1e-9;
+
+// temperatures compare by their celsius value, so 212°F == 100°C and 0°C < 273.16 K
+// anything within COMPARE_TOLERANCE is equal, which also means == isn't transitive over
+// long chains of almost-equal values, and NaN never compares to anything
+impl PartialEq for Temperature {
+    fn eq(&self, other: &Self) -> bool {
+        (self.to_celsius() - other.to_celsius()).abs() <= COMPARE_TOLERANCE
+    }
+}
+
+impl PartialOrd for Temperature {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        self.to_celsius().partial_cmp(&other.to_celsius())
+    }
+}
+
+impl Temperature {
+    pub fn to_celsius(&self) -> f64 {
+        match self {
+            Temperature::Celsius(c) => *c,
+            Temperature::Fahrenheit(f) => fahrenheit_to_celsius(*f),
+            Temperature::Kelvin(k) => kelvin_to_celsius(*k),
+            Temperature::Rankine(r) => kelvin_to_celsius(rankine_to_kelvin(*r)),
+            Temperature::Newton(n) => newton_to_celsius(*n),
+            Temperature::Reaumur(re) => reaumur_to_celsius(*re),
+        }
+    }
+
+    pub fn to_fahrenheit(&self) -> f64 {
+        match self {
+            Temperature::Fahrenheit(f) => *f,
+            Temperature::Rankine(r) => rankine_to_fahrenheit(*r),
+            _ => celsius_to_fahrenheit(self.to_celsius()),
+        }
+    }
+
+    pub fn to_kelvin(&self) -> f64 {
+        match self {
+            Temperature::Kelvin(k) => *k,
+            Temperature::Rankine(r) => rankine_to_kelvin(*r),
+            _ => celsius_to_kelvin(self.to_celsius()),
+        }
+    }
+
+    pub fn to_rankine(&self) -> f64 {
+        match self {
+            Temperature::Rankine(r) => *r,
+            Temperature::Kelvin(k) => kelvin_to_rankine(*k),
+            _ => fahrenheit_to_rankine(self.to_fahrenheit()),
+        }
+    }
+
+    pub fn to_newton(&self) -> f64 {
+        match self {
+            Temperature::Newton(n) => *n,
+            _ => celsius_to_newton(self.to_celsius()),
+        }
+    }
+
+    pub fn to_reaumur(&self) -> f64 {
+        match self {
+            Temperature::Reaumur(re) => *re,
+            _ => celsius_to_reaumur(self.to_celsius()),
+        }
+    }
+}
+
+impl Unit {
+    // every unit, in the order they're listed everywhere else
+    pub const ALL: [Unit; 6] = [
+        Unit::Celsius,
+        Unit::Fahrenheit,
+        Unit::Kelvin,
+        Unit::Rankine,
+        Unit::Newton,
+        Unit::Reaumur,
+    ];
+
+    // the unit's full name in lowercase, i.e. "celsius"
+    pub fn name(self) -> &'static str {
+        match self {
+            Unit::Celsius => "celsius",
+            Unit::Fahrenheit => "fahrenheit",
+            Unit::Kelvin => "kelvin",
+            Unit::Rankine => "rankine",
+            Unit::Newton => "newton",
+            Unit::Reaumur => "reaumur",
+        }
+    }
+
+    pub fn temperature(self, value: f64) -> Temperature {
+        match self {
+            Unit::Celsius => Temperature::Celsius(value),
+            Unit::Fahrenheit => Temperature::Fahrenheit(value),
+            Unit::Kelvin => Temperature::Kelvin(value),
+            Unit::Rankine => Temperature::Rankine(value),
+            Unit::Newton => Temperature::Newton(value),
+            Unit::Reaumur => Temperature::Reaumur(value),
+        }
+    }
+}
+
+pub const DEFAULT_PRECISION: u32 = 2;
+
+// the most decimal places a result can be rounded to, an f64 only has about 15 to 17
+// significant digits and 10^decimals stops fitting in one not long after
+pub const MAX_PRECISION: u32 = 15;
+
+// how a result gets rounded to its decimal places
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RoundMode {
+    #[default]
+    Nearest,
+    Floor,
+    Ceil,
+}
+
+/// rounds to the given number of decimal places, halves round away from zero
+///
+/// ```
+/// use learning_rust::temperature::round_to;
+///
+/// assert_eq!(round_to(37.777, 2), 37.78);
+/// ```
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    round_with(value, decimals, RoundMode::Nearest)
+}
+
+// how close, relative to its size, a scaled value has to be to a whole number for round_with
+// to treat it as that number, float error from the scaling is far smaller than this
+const ROUND_SNAP_EPSILON: f64 = 1e-12;
+
+/// like round_to, but floor and ceil always go down or up to the given decimal place
+/// more than MAX_PRECISION decimals can't be rounded to, so the value is given back as is,
+/// and so is a value too big to scale, it has no digits that far down anyway
+///
+/// ```
+/// use learning_rust::temperature::{round_with, RoundMode};
+///
+/// assert_eq!(round_with(37.777, 2, RoundMode::Floor), 37.77);
+/// ```
+pub fn round_with(value: f64, decimals: u32, mode: RoundMode) -> f64 {
+    if decimals > MAX_PRECISION {
+        return value;
+    }
+
+    let factor = 10f64.powi(decimals as i32);
+    let mut scaled = value * factor;
+    if !scaled.is_finite() {
+        return value;
+    }
+
+    // 0.29 * 100 comes out as 28.999999999999996, which floor would take down to 28
+    // so a value that's only float error away from a whole step is put on that step first
+    if (scaled - scaled.round()).abs() <= scaled.abs() * ROUND_SNAP_EPSILON {
+        scaled = scaled.round();
+    }
+
+    let rounded = match mode {
+        RoundMode::Nearest => scaled.round(),
+        RoundMode::Floor => scaled.floor(),
+        RoundMode::Ceil => scaled.ceil(),
+    };
+
+    rounded / factor
+}
+
+/// rounds to the nearest half degree for easy reading, i.e. 37.2 is 37 and 37.3 is 37.5
+/// quarters sit exactly between two halves and round away from zero like round_to does
+///
+/// ```
+/// use learning_rust::temperature::round_nice;
+///
+/// assert_eq!(round_nice(37.77), 38.0);
+/// assert_eq!(round_nice(37.3), 37.5);
+/// ```
+pub fn round_nice(value: f64) -> f64 {
+    (value * 2.0).round() / 2.0
+}
+
+/// formats with exactly `decimals` places, so results always print the same way
+/// anything that comes out as zero, -0.0 included, is printed without a minus sign
+///
+/// ```
+/// use learning_rust::temperature::format_result;
+///
+/// assert_eq!(format_result(37.0, 2), "37.00");
+/// assert_eq!(format_result(-0.0, 2), "0.00");
+/// ```
+pub fn format_result(value: f64, decimals: u32) -> String {
+    let formatted = format!("{:.*}", decimals as usize, value);
+    match formatted.strip_prefix('-') {
+        Some(digits) if digits.chars().all(|c| c == '0' || c == '.') => digits.to_string(),
+        _ => formatted,
+    }
+}
+
+// temperatures go through celsius, i.e. fahrenheit is (value - 32) × 5/9
+impl UnitKind for Unit {
+    fn to_base_factor(self) -> f64 {
+        match self {
+            Unit::Celsius | Unit::Kelvin => 1.0,
+            Unit::Fahrenheit | Unit::Rankine => 5.0 / 9.0,
+            Unit::Newton => 100.0 / 33.0,
+            Unit::Reaumur => 5.0 / 4.0,
+        }
+    }
+
+    fn offset(self) -> f64 {
+        match self {
+            Unit::Celsius | Unit::Newton | Unit::Reaumur => 0.0,
+            Unit::Fahrenheit => -32.0,
+            Unit::Kelvin => -273.15,
+            Unit::Rankine => -491.67,
+        }
+    }
+}
+
+// accepts the unit's letter or full name in any case, i.e. "c", "C", or "Celsius"
+// "r" is already rankine, so réaumur goes by "re", with or without the accent
+impl FromStr for Unit {
+    type Err = TempError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "c" | "celsius" => Ok(Unit::Celsius),
+            "f" | "fahrenheit" => Ok(Unit::Fahrenheit),
+            "k" | "kelvin" => Ok(Unit::Kelvin),
+            "r" | "rankine" => Ok(Unit::Rankine),
+            "n" | "newton" => Ok(Unit::Newton),
+            "re" | "ré" | "reaumur" | "réaumur" => Ok(Unit::Reaumur),
+            _ => Err(TempError::UnknownUnit(s.trim().to_string())),
+        }
+    }
+}
+
+// parses a value with its unit on the end straight into a Temperature, i.e. "212F" or
+// "273.15K", see parse_temperature_tagged for everything that's accepted
+impl TryFrom<&str> for Temperature {
+    type Error = TempError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (value, unit) = parse_temperature_tagged(s)?;
+        Ok(unit.temperature(value))
+    }
+}
+
+/// converts a bare value from one unit to another
+///
+/// ```
+/// use learning_rust::temperature::{convert, Unit};
+///
+/// assert!((convert(212.0, Unit::Fahrenheit, Unit::Kelvin) - 373.15).abs() < 1e-9);
+/// ```
+pub fn convert(value: f64, from: Unit, to: Unit) -> f64 {
+    let temp = from.temperature(value);
+
+    match to {
+        Unit::Celsius => temp.to_celsius(),
+        Unit::Fahrenheit => temp.to_fahrenheit(),
+        Unit::Kelvin => temp.to_kelvin(),
+        Unit::Rankine => temp.to_rankine(),
+        Unit::Newton => temp.to_newton(),
+        Unit::Reaumur => temp.to_reaumur(),
+    }
+}
+
+/// convert() done entirely in f32, to show how much precision the narrower type loses
+/// every unit goes through celsius, the same formulas as the f64 functions
+///
+/// ```
+/// use learning_rust::temperature::{convert_f32, Unit};
+///
+/// assert_eq!(convert_f32(212.0, Unit::Fahrenheit, Unit::Celsius), 100.0);
+/// ```
+pub fn convert_f32(value: f32, from: Unit, to: Unit) -> f32 {
+    if from == to {
+        return value;
+    }
+
+    let celsius = match from {
+        Unit::Celsius => value,
+        Unit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+        Unit::Kelvin => value - 273.15,
+        Unit::Rankine => value * 5.0 / 9.0 - 273.15,
+        Unit::Newton => value * 100.0 / 33.0,
+        Unit::Reaumur => value * 5.0 / 4.0,
+    };
+
+    match to {
+        Unit::Celsius => celsius,
+        Unit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        Unit::Kelvin => celsius + 273.15,
+        Unit::Rankine => (celsius + 273.15) * 9.0 / 5.0,
+        Unit::Newton => celsius * 33.0 / 100.0,
+        Unit::Reaumur => celsius * 4.0 / 5.0,
+    }
+}
+
+/// the f64 counterpart to convert_f32, which is just convert()
+///
+/// ```
+/// use learning_rust::temperature::{convert_f64, Unit};
+///
+/// assert_eq!(convert_f64(212.0, Unit::Fahrenheit, Unit::Celsius), 100.0);
+/// ```
+pub fn convert_f64(value: f64, from: Unit, to: Unit) -> f64 {
+    convert(value, from, to)
+}
+
+/// the value in every unit, in the order of Unit::ALL, the `from` unit included
+///
+/// ```
+/// use learning_rust::temperature::{convert_all, Unit};
+///
+/// let all = convert_all(100.0, Unit::Celsius);
+/// assert_eq!(all[0], (Unit::Celsius, 100.0));
+/// assert_eq!(all.len(), Unit::ALL.len());
+/// ```
+pub fn convert_all(value: f64, from: Unit) -> Vec<(Unit, f64)> {
+    Unit::ALL.iter().map(|&to| (to, convert(value, from, to))).collect()
+}
+
+/// the worst error from converting each sample F→C→F and C→F→C
+/// in exact math both trips land back where they started, so anything left over is float error
+///
+/// ```
+/// use learning_rust::temperature::max_roundtrip_error;
+///
+/// assert!(max_roundtrip_error(&[-40.0, 0.0, 98.6]) < 1e-9);
+/// ```
+pub fn max_roundtrip_error(samples: &[f64]) -> f64 {
+    samples
+        .iter()
+        .flat_map(|&value| {
+            let via_celsius = celsius_to_fahrenheit(fahrenheit_to_celsius(value));
+            let via_fahrenheit = fahrenheit_to_celsius(celsius_to_fahrenheit(value));
+            [(via_celsius - value).abs(), (via_fahrenheit - value).abs()]
+        })
+        .fold(0.0, f64::max)
+}
+
+/// how far off the input comes back after converting, rounding to `decimals`, and converting
+/// back, i.e. the information lost by the rounding, in the input's unit
+///
+/// ```
+/// use learning_rust::temperature::{roundtrip_delta, Unit};
+///
+/// // 100°F is 37.78°C, shown as 38°C that goes back to 100.4°F
+/// let delta = roundtrip_delta(100.0, Unit::Fahrenheit, Unit::Celsius, 0);
+/// assert!((delta - 0.4).abs() < 1e-9);
+/// ```
+pub fn roundtrip_delta(input: f64, from: Unit, to: Unit, decimals: u32) -> f64 {
+    let shown = round_to(convert(input, from, to), decimals);
+    (convert(shown, to, from) - input).abs()
+}
+
+/// converts every value in order, an empty slice just gives back an empty Vec
+///
+/// ```
+/// use learning_rust::temperature::{convert_batch, Unit};
+///
+/// assert_eq!(convert_batch(&[0.0, 100.0], Unit::Celsius, Unit::Fahrenheit), vec![32.0, 212.0]);
+/// ```
+pub fn convert_batch(values: &[f64], from: Unit, to: Unit) -> Vec<f64> {
+    // the units are matched once out here rather than for every value like convert() would,
+    // so each pair gets its own tight loop the compiler can vectorize
+    match from {
+        Unit::Celsius => convert_batch_with(values, Temperature::Celsius, to),
+        Unit::Fahrenheit => convert_batch_with(values, Temperature::Fahrenheit, to),
+        Unit::Kelvin => convert_batch_with(values, Temperature::Kelvin, to),
+        Unit::Rankine => convert_batch_with(values, Temperature::Rankine, to),
+        Unit::Newton => convert_batch_with(values, Temperature::Newton, to),
+        Unit::Reaumur => convert_batch_with(values, Temperature::Reaumur, to),
+    }
+}
+
+/// like convert_batch, but each value is overwritten with its result instead of going into
+/// a new Vec, so the slice keeps its length and order
+///
+/// ```
+/// use learning_rust::temperature::{convert_batch_in_place, Unit};
+///
+/// let mut values = [0.0, 100.0];
+/// convert_batch_in_place(&mut values, Unit::Celsius, Unit::Fahrenheit);
+/// assert_eq!(values, [32.0, 212.0]);
+/// ```
+pub fn convert_batch_in_place(values: &mut [f64], from: Unit, to: Unit) {
+    // iter_mut hands out one &mut at a time, so each value can be read and replaced
+    for value in values.iter_mut() {
+        *value = convert(*value, from, to);
+    }
+}
+
+/// converts values one at a time as they're asked for, so nothing is collected up front
+/// and an endless source is fine as long as something like take() stops it
+///
+/// ```
+/// use learning_rust::temperature::{convert_iter, Unit};
+///
+/// let mut results = convert_iter(std::iter::repeat(100.0), Unit::Celsius, Unit::Fahrenheit);
+/// assert_eq!(results.next(), Some(212.0));
+/// ```
+pub fn convert_iter<I: Iterator<Item = f64>>(
+    iter: I,
+    from: Unit,
+    to: Unit,
+) -> impl Iterator<Item = f64> {
+    iter.map(move |value| convert(value, from, to))
+}
+
+// takes the `from` unit as its Temperature constructor so it's known at compile time,
+// the results are exactly what convert() gives since the same methods do the work
+fn convert_batch_with<F: Fn(f64) -> Temperature>(values: &[f64], from: F, to: Unit) -> Vec<f64> {
+    let values = values.iter().map(|&value| from(value));
+
+    match to {
+        Unit::Celsius => values.map(|temp| temp.to_celsius()).collect(),
+        Unit::Fahrenheit => values.map(|temp| temp.to_fahrenheit()).collect(),
+        Unit::Kelvin => values.map(|temp| temp.to_kelvin()).collect(),
+        Unit::Rankine => values.map(|temp| temp.to_rankine()).collect(),
+        Unit::Newton => values.map(|temp| temp.to_newton()).collect(),
+        Unit::Reaumur => values.map(|temp| temp.to_reaumur()).collect(),
+    }
+}
+
+/// keeps a reading inside a sensor's operating range
+///
+/// ```
+/// use learning_rust::temperature::clamp_temp;
+///
+/// assert_eq!(clamp_temp(150.0, -40.0, 125.0), 125.0);
+/// ```
+pub fn clamp_temp(value: f64, min: f64, max: f64) -> f64 {
+    debug_assert!(min <= max, "clamp_temp called with min {min} above max {max}");
+    value.max(min).min(max)
+}
+
+/// like convert_batch, but readings are first clamped to [min, max] to flatten sensor spikes
+/// min and max are in the `from` unit
+///
+/// ```
+/// use learning_rust::temperature::{convert_batch_clamped, Unit};
+///
+/// let celsius = convert_batch_clamped(&[-50.0, 150.0], Unit::Celsius, Unit::Celsius, -40.0, 125.0);
+/// assert_eq!(celsius, vec![-40.0, 125.0]);
+/// ```
+pub fn convert_batch_clamped(values: &[f64], from: Unit, to: Unit, min: f64, max: f64) -> Vec<f64> {
+    values
+        .iter()
+        .map(|&value| convert(clamp_temp(value, min, max), from, to))
+        .collect()
+}
+
+/// converts first and then clamps the result to [lo, hi], for sensors that can only report
+/// within a fixed range
+/// unlike convert_batch_clamped, lo and hi are in the `to` unit, not the `from` unit
+///
+/// ```
+/// use learning_rust::temperature::{convert_saturating, Unit};
+///
+/// assert_eq!(convert_saturating(500.0, Unit::Fahrenheit, Unit::Celsius, -40.0, 125.0), 125.0);
+/// ```
+pub fn convert_saturating(value: f64, from: Unit, to: Unit, lo: f64, hi: f64) -> f64 {
+    clamp_temp(convert(value, from, to), lo, hi)
+}
+
+/// -40 is the one temperature where celsius and fahrenheit read the same
+///
+/// ```
+/// use learning_rust::temperature::is_crossover;
+///
+/// assert!(is_crossover(-40.0));
+/// assert!(!is_crossover(0.0));
+/// ```
+pub fn is_crossover(f: f64) -> bool {
+    (f - -40.0).abs() < 1e-9
+}
+
+/// puts a celsius temperature into a rough "feels like" bucket
+/// each bucket includes its lower edge, so 0 is cold, 10 is mild, and 20 is warm,
+/// while 30 is the last warm temperature and only above that counts as hot
+///
+/// ```
+/// use learning_rust::temperature::describe;
+///
+/// assert_eq!(describe(25.0), "warm");
+/// ```
+pub fn describe(celsius: f64) -> &'static str {
+    if celsius < 0.0 {
+        "freezing"
+    } else if celsius < 10.0 {
+        "cold"
+    } else if celsius < 20.0 {
+        "mild"
+    } else if celsius <= 30.0 {
+        "warm"
+    } else {
+        "hot"
+    }
+}
+
+// prints the value followed by its unit symbol, i.e. 98.6°F
+// a precision can be passed through the format string, i.e. {:.2}, then the value goes
+// through format_result so -0.0 doesn't print as "-0.00"
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (value, symbol) = match self {
+            Temperature::Celsius(c) => (c, "°C"),
+            Temperature::Fahrenheit(degf) => (degf, "°F"),
+            Temperature::Kelvin(k) => (k, "K"),
+            Temperature::Rankine(r) => (r, "°R"),
+            Temperature::Newton(n) => (n, "°N"),
+            Temperature::Reaumur(re) => (re, "°Ré"),
+        };
+
+        match f.precision() {
+            Some(precision) => write!(f, "{}{symbol}", format_result(*value, precision as u32)),
+            None => write!(f, "{value}{symbol}"),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::error::TempError;
+use crate::temperature::{celsius_to_fahrenheit, fahrenheit_to_celsius};
+
+// constants for the Magnus formula (Sonntag 1990), good from about -45°C to 60°C
+const MAGNUS_A: f64 = 17.62;
+const MAGNUS_B: f64 = 243.12;
+
+fn check_humidity(relative_humidity: f64) -> Result<f64, TempError> {
+    if (0.0..=100.0).contains(&relative_humidity) {
+        Ok(relative_humidity)
+    } else {
+        Err(TempError::HumidityOutOfRange(relative_humidity))
+    }
+}
+
+// the temperature air has to cool to before water starts condensing out of it, in celsius
+// relative humidity is a percentage from 0 to 100, 0% has no dew point so it comes back as -inf
+pub fn dew_point(temp_c: f64, relative_humidity: f64) -> Result<f64, TempError> {
+    let relative_humidity = check_humidity(relative_humidity)?;
+    // ln(0) is -inf, which would turn into inf / inf = NaN in the formula below
+    if relative_humidity == 0.0 {
+        return Ok(f64::NEG_INFINITY);
+    }
+
+    let gamma = (relative_humidity / 100.0).ln() + MAGNUS_A * temp_c / (MAGNUS_B + temp_c);
+    Ok(MAGNUS_B * gamma / (MAGNUS_A - gamma))
+}
+
+// how hot it feels once humidity is taken into account, using the NOAA Rothfusz regression
+// the regression only holds from 80°F up, so cooler temperatures come back unchanged
+// humidity is clamped to 0-100 rather than rejected
+pub fn heat_index(temp_f: f64, humidity: f64) -> f64 {
+    if temp_f < 80.0 {
+        return temp_f;
+    }
+
+    let t = temp_f;
+    let r = humidity.clamp(0.0, 100.0);
+
+    -42.379 + 2.04901523 * t + 10.14333127 * r
+        - 0.22475541 * t * r
+        - 0.00683783 * t * t
+        - 0.05481717 * r * r
+        + 0.00122874 * t * t * r
+        + 0.00085282 * t * r * r
+        - 0.00000199 * t * t * r * r
+}
+
+// heat_index for a celsius reading, the answer comes back in celsius too
+pub fn heat_index_celsius(temp_c: f64, humidity: f64) -> f64 {
+    fahrenheit_to_celsius(heat_index(celsius_to_fahrenheit(temp_c), humidity))
+}
+
+// how cold it feels in the wind, using the NWS wind chill formula
+// the formula is only defined at or below 50°F with wind above 3 mph, outside of that
+// the dry temperature is returned unchanged
+pub fn wind_chill(temp_f: f64, wind_mph: f64) -> f64 {
+    if temp_f > 50.0 || wind_mph <= 3.0 {
+        return temp_f;
+    }
+
+    let wind = wind_mph.powf(0.16);
+    35.74 + 0.6215 * temp_f - 35.75 * wind + 0.4275 * temp_f * wind
+}
+
+// wind_chill for a celsius reading, the wind is still in mph
+pub fn wind_chill_celsius(temp_c: f64, wind_mph: f64) -> f64 {
+    fahrenheit_to_celsius(wind_chill(celsius_to_fahrenheit(temp_c), wind_mph))
+}
@@ -0,0 +1,120 @@
+use std::env;
+use std::fs;
+use std::process;
+use std::path::Path;
+use learning_rust::app::{route, AppError, Command, FileOutput, USAGE};
+use learning_rust::mass::MassUnit;
+use learning_rust::{TempError, Unit};
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn routes_each_subcommand() {
+    let convert = args(&["convert", "98.6", "--from", "f", "--to", "c"]);
+    assert_eq!(route(&convert), Ok(Command::Convert(&convert[1..])));
+    assert_eq!(route(&args(&["fib", "10"])), Ok(Command::Fib(10)));
+    assert_eq!(route(&args(&["song"])), Ok(Command::Song));
+    assert_eq!(route(&args(&["guess"])), Ok(Command::Guess));
+    assert_eq!(
+        route(&args(&["mass", "1", "lb", "g"])),
+        Ok(Command::Mass(1.0, MassUnit::Pounds, MassUnit::Grams))
+    );
+    assert_eq!(
+        route(&args(&["file", "temps.txt", "f", "c"])),
+        Ok(Command::File(Path::new("temps.txt"), Unit::Fahrenheit, Unit::Celsius, FileOutput::Lines, None))
+    );
+    assert_eq!(
+        route(&args(&["file", "temps.txt", "--summary", "f", "c"])),
+        Ok(Command::File(Path::new("temps.txt"), Unit::Fahrenheit, Unit::Celsius, FileOutput::Summary, None))
+    );
+    assert_eq!(
+        route(&args(&["file", "--max-kelvin", "400", "temps.txt", "c", "k"])),
+        Ok(Command::File(Path::new("temps.txt"), Unit::Celsius, Unit::Kelvin, FileOutput::Lines, Some(400.0)))
+    );
+    assert_eq!(
+        route(&args(&["file", "temps.txt", "f", "c", "--csv"])),
+        Ok(Command::File(Path::new("temps.txt"), Unit::Fahrenheit, Unit::Celsius, FileOutput::Csv, None))
+    );
+    assert!(matches!(
+        route(&args(&["file", "temps.txt", "f", "c", "--csv", "--summary"])),
+        Err(AppError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn no_arguments_runs_the_converter() {
+    assert_eq!(route(&[]), Ok(Command::Convert(&[])));
+}
+
+#[test]
+fn unknown_subcommand_is_an_error() {
+    assert_eq!(
+        route(&args(&["dance"])),
+        Err(AppError::UnknownCommand(String::from("dance")))
+    );
+}
+
+#[test]
+fn skipped_piped_lines_exit_with_the_first_lines_code() {
+    let parse = AppError::SkippedLines(TempError::Line(1, Box::new(TempError::ParseFailed(String::from("'abc'")))));
+    let range = AppError::SkippedLines(TempError::Line(2, Box::new(TempError::BelowAbsoluteZero)));
+    assert_eq!(parse.exit_code(), 2);
+    assert_eq!(range.exit_code(), 3);
+}
+
+#[test]
+fn help_and_version_ignore_a_broken_config() {
+    let dir = env::temp_dir().join(format!("learning_rust_{}_bad_config", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(".tempconfig"), "not a key value line\n").unwrap();
+
+    let run = |flag: &str| {
+        process::Command::new(env!("CARGO_BIN_EXE_learning_rust"))
+            .arg(flag)
+            .current_dir(&dir)
+            .output()
+            .unwrap()
+    };
+    let help = run("--help");
+    let version = run("--version");
+    let convert = run("convert");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(help.status.success());
+    assert!(String::from_utf8(help.stdout).unwrap().starts_with("usage:"));
+    assert!(version.status.success());
+    assert!(String::from_utf8(version.stdout).unwrap().starts_with("learning_rust "));
+    // anything that does need the config still reports it
+    assert_eq!(convert.status.code(), Some(2));
+}
+
+#[test]
+fn usage_lists_every_convert_flag() {
+    let flags = [
+        "--decimals", "--round ", "--quiet", "-q", "--format", "--explain", "--no-color", "--log",
+        "--strict", "--max-kelvin", "--precision", "--round-trip-check", "--table", "--summary",
+    ];
+    for flag in flags {
+        assert!(USAGE.contains(flag), "{flag} is missing from the usage");
+    }
+}
+
+#[test]
+fn file_csv_uses_the_config_precision() {
+    let dir = env::temp_dir().join(format!("learning_rust_{}_csv", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("temps.txt"), "32\n100\n").unwrap();
+    fs::write(dir.join(".tempconfig"), "precision=1\n").unwrap();
+
+    let output = process::Command::new(env!("CARGO_BIN_EXE_learning_rust"))
+        .args(["file", "temps.txt", "f", "c", "--csv"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "input,result\n32,0.0\n100,37.8\n");
+}
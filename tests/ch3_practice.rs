@@ -0,0 +1,53 @@
+use learning_rust::ch3_practice::{parse_direction, parse_precision, twelve_days, Direction};
+use learning_rust::Unit;
+use learning_rust::temperature::MAX_PRECISION;
+
+#[test]
+fn menu_numbers_pick_a_direction() {
+    assert_eq!(parse_direction("1"), Some(Direction::FahrenheitToCelsius));
+    assert_eq!(parse_direction("2"), Some(Direction::CelsiusToFahrenheit));
+    assert_eq!(parse_direction("3"), Some(Direction::FahrenheitToKelvin));
+    assert_eq!(parse_direction(" 4\n"), Some(Direction::CelsiusToKelvin));
+}
+
+#[test]
+fn written_directions_pick_a_direction() {
+    assert_eq!(parse_direction("f to c"), Some(Direction::FahrenheitToCelsius));
+    assert_eq!(parse_direction("Celsius to Fahrenheit"), Some(Direction::CelsiusToFahrenheit));
+    assert_eq!(parse_direction("F TO K"), Some(Direction::FahrenheitToKelvin));
+    assert_eq!(parse_direction("celsius to k\n"), Some(Direction::CelsiusToKelvin));
+    assert_eq!(Direction::CelsiusToKelvin.units(), (Unit::Celsius, Unit::Kelvin));
+}
+
+#[test]
+fn unknown_directions_are_none() {
+    assert_eq!(parse_direction("5"), None);
+    assert_eq!(parse_direction(""), None);
+    assert_eq!(parse_direction("k to c"), None);
+    assert_eq!(parse_direction("f to x"), None);
+    assert_eq!(parse_direction("fahrenheit"), None);
+}
+
+#[test]
+fn precision_is_a_number_of_places_up_to_the_max() {
+    assert_eq!(parse_precision(""), Some(2));
+    assert_eq!(parse_precision(" 3 \n"), Some(3));
+    assert_eq!(parse_precision("0"), Some(0));
+    assert_eq!(parse_precision("15"), Some(MAX_PRECISION));
+    assert_eq!(parse_precision("16"), None);
+    assert_eq!(parse_precision("-1"), None);
+    assert_eq!(parse_precision("two"), None);
+}
+
+#[test]
+fn twelve_days_has_twelve_verses() {
+    let song = twelve_days();
+    let verses: Vec<&str> = song.split("\n\n").collect();
+    assert_eq!(verses.len(), 12);
+
+    assert!(verses[0].ends_with("sent to me:\na partridge in a pear tree"), "{}", verses[0]);
+    let last = verses[11];
+    assert!(last.starts_with("On the twelfth day of Christmas"), "{last}");
+    assert!(last.contains("twelve drummers drumming"), "{last}");
+    assert!(last.contains("and a partridge in a pear tree"), "{last}");
+}
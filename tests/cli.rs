@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use learning_rust::cli::{
+    colorize, explain, format_output, from_vars, parse_args, parse_args_with, split_strict_flags,
+    to_json, ArgError, ConvertRequest, FloatWidth, ParsedArgs,
+};
+use learning_rust::config::Config;
+use learning_rust::temperature::SUN_SURFACE_KELVIN;
+use learning_rust::{TempError, Unit};
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+fn request(line: &str) -> ConvertRequest {
+    match parse_args(&args(line)) {
+        Ok(ParsedArgs::Convert(req)) => req,
+        other => panic!("expected a conversion, got {other:?}"),
+    }
+}
+
+#[test]
+fn parses_a_full_request() {
+    let req = request("98.6 --from f --to c");
+    assert_eq!(req.value, 98.6);
+    assert_eq!(req.from, Unit::Fahrenheit);
+    assert_eq!(req.to, Unit::Celsius);
+}
+
+#[test]
+fn missing_flags_are_errors() {
+    assert_eq!(parse_args(&args("98.6 --to c")), Err(ArgError::MissingFlag("--from")));
+    assert_eq!(parse_args(&args("98.6 --from f")), Err(ArgError::MissingFlag("--to")));
+}
+
+#[test]
+fn decimals_above_the_max_are_errors() {
+    assert_eq!(request("98.6 --from f --to c --decimals 15").precision, 15);
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to c --decimals 400")),
+        Err(ArgError::InvalidPrecision(String::from("400")))
+    );
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to c --decimals 4294967295")),
+        Err(ArgError::InvalidPrecision(String::from("4294967295")))
+    );
+}
+
+#[test]
+fn duplicate_flags_are_errors() {
+    assert_eq!(parse_args(&args("98.6 --from f --from c --to k")), Err(ArgError::DuplicateFlag("--from")));
+    assert_eq!(parse_args(&args("98.6 --from f --to c --to k")), Err(ArgError::DuplicateFlag("--to")));
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to c --precision f32 --precision f64")),
+        Err(ArgError::DuplicateFlag("--precision"))
+    );
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to c --format text --format json")),
+        Err(ArgError::DuplicateFlag("--format"))
+    );
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to c --round floor --round ceil")),
+        Err(ArgError::DuplicateFlag("--round"))
+    );
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to c --log a.log --log b.log")),
+        Err(ArgError::DuplicateFlag("--log"))
+    );
+}
+
+#[test]
+fn unknown_units_are_errors() {
+    assert_eq!(
+        parse_args(&args("98.6 --from x --to c")),
+        Err(ArgError::UnknownUnit(String::from("x")))
+    );
+}
+
+#[test]
+fn quiet_output_is_just_the_number() {
+    let req = request("98.6 --from f --to c -q");
+    let output = format_output(&req, 37.0);
+    assert_eq!(output, "37.00");
+    assert!(!output.contains("->") && !output.contains('°'));
+}
+
+#[test]
+fn output_always_has_the_requested_decimals() {
+    let req = request("98.6 --from f --to c --decimals 1 --no-color");
+    assert_eq!(format_output(&req, 37.0), "98.6°F -> 37.0°C");
+
+    // a result that rounds to zero from below doesn't print as -0.00
+    let req = request("31.999 --from f --to c -q");
+    assert_eq!(format_output(&req, -0.000555), "0.00");
+}
+
+#[test]
+fn json_output() {
+    let req = request("98.6 --from f --to c --format json");
+    assert_eq!(
+        to_json(&req, 37.0),
+        r#"{"input":98.6,"from":"fahrenheit","to":"celsius","result":37.0}"#
+    );
+}
+
+#[test]
+fn huge_results_stay_valid_json() {
+    let req = request("1e300 --from c --to k --decimals 15 --format json");
+    let output = format_output(&req, 1e300);
+    assert!(!output.contains("inf"), "{output}");
+    assert!(output.ends_with(r#""result":1e300}"#), "{output}");
+}
+
+#[test]
+fn explains_fahrenheit_to_celsius() {
+    let req = request("98.6 --from f --to c --explain");
+    assert_eq!(explain(&req, 37.0), "C = (98.6 - 32) × 5/9 = 37.00");
+}
+
+#[test]
+fn explained_results_just_under_zero_have_no_minus() {
+    let req = request("31.999 --from f --to n --explain");
+    assert_eq!(explain(&req, -0.0002), "N = 0.00 × 33/100 = 0.00");
+}
+
+#[test]
+fn explains_celsius_to_kelvin() {
+    let req = request("25 --from c --to k");
+    assert_eq!(explain(&req, 298.15), "K = 25 + 273.15 = 298.15");
+}
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn env_request_needs_every_variable() {
+    let env = vars(&[("TEMP_VALUE", "98.6"), ("TEMP_FROM", "f")]);
+    assert!(from_vars(|name| env.get(name).cloned()).is_none());
+}
+
+#[test]
+fn env_request_parses_values() {
+    let env = vars(&[("TEMP_VALUE", "98.6"), ("TEMP_FROM", "f"), ("TEMP_TO", "celsius")]);
+    let req = from_vars(|name| env.get(name).cloned()).unwrap().unwrap();
+    assert_eq!(req, ConvertRequest::new(98.6, Unit::Fahrenheit, Unit::Celsius));
+}
+
+#[test]
+fn env_request_surfaces_bad_values() {
+    let env = vars(&[("TEMP_VALUE", "98.6"), ("TEMP_FROM", "f"), ("TEMP_TO", "rankin")]);
+    let err = from_vars(|name| env.get(name).cloned()).unwrap().unwrap_err();
+    assert_eq!(err, TempError::UnknownUnit(String::from("rankin")));
+}
+
+#[test]
+fn no_color_has_no_escape_codes() {
+    let req = request("98.6 --from f --to c --no-color");
+    let output = format_output(&req, 37.0);
+    assert_eq!(output, "98.6°F -> 37.00°C");
+    assert!(!output.contains('\x1b'));
+}
+
+#[test]
+fn color_follows_describe() {
+    assert_eq!(colorize("-5°C", -5.0), "\x1b[34m-5°C\x1b[0m");
+    assert_eq!(colorize("15°C", 15.0), "\x1b[32m15°C\x1b[0m");
+    assert_eq!(colorize("40°C", 40.0), "\x1b[31m40°C\x1b[0m");
+}
+
+#[test]
+fn builder_builds_a_complete_request() {
+    let req = ConvertRequest::builder()
+        .value(98.6)
+        .from(Unit::Fahrenheit)
+        .to(Unit::Celsius)
+        .build()
+        .unwrap();
+    assert_eq!(req, ConvertRequest::new(98.6, Unit::Fahrenheit, Unit::Celsius));
+    assert_eq!(req.precision, 2);
+    assert!(!req.quiet);
+}
+
+#[test]
+fn builder_needs_a_value() {
+    let err = ConvertRequest::builder().from(Unit::Fahrenheit).to(Unit::Celsius).build();
+    assert_eq!(err, Err(ArgError::MissingValue));
+}
+
+#[test]
+fn parses_a_single_line_spec() {
+    let req: ConvertRequest = "98.6 f c".parse().unwrap();
+    assert_eq!(req, ConvertRequest::new(98.6, Unit::Fahrenheit, Unit::Celsius));
+    assert_eq!(
+        "98.6 f x".parse::<ConvertRequest>(),
+        Err(ArgError::UnknownUnit(String::from("x")))
+    );
+    assert_eq!("98.6 f".parse::<ConvertRequest>(), Err(ArgError::WrongTokenCount(2)));
+    assert_eq!("98.6 f c k".parse::<ConvertRequest>(), Err(ArgError::WrongTokenCount(4)));
+}
+
+#[test]
+fn help_and_version_short_circuit() {
+    assert_eq!(parse_args(&args("--help")), Ok(ParsedArgs::Help));
+    assert_eq!(parse_args(&args("-h")), Ok(ParsedArgs::Help));
+    assert_eq!(parse_args(&args("--version")), Ok(ParsedArgs::Version));
+    assert_eq!(parse_args(&args("abc --from x --version")), Ok(ParsedArgs::Version));
+    assert_eq!(parse_args(&args("98.6 --help --version")), Ok(ParsedArgs::Help));
+}
+
+#[test]
+fn precision_picks_the_float_width() {
+    assert_eq!(request("98.6 --from f --to c").float_width, FloatWidth::F64);
+    assert_eq!(request("98.6 --from f --to c --precision f32").float_width, FloatWidth::F32);
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to c --precision f16")),
+        Err(ArgError::UnknownFloatWidth(String::from("f16")))
+    );
+}
+
+#[test]
+fn strict_mode_sets_a_maximum() {
+    assert_eq!(request("10000 --from c --to k").max_kelvin, None);
+    assert_eq!(request("10000 --from c --to k --strict").max_kelvin, Some(SUN_SURFACE_KELVIN));
+    assert_eq!(request("10000 --from c --to k --max-kelvin 1000").max_kelvin, Some(1000.0));
+    assert_eq!(
+        parse_args(&args("10 --from c --to k --max-kelvin -3")),
+        Err(ArgError::InvalidMaxKelvin(String::from("-3")))
+    );
+    assert_eq!(
+        parse_args(&args("10 --from c --to k --strict --max-kelvin 1000")),
+        Err(ArgError::DuplicateFlag("--strict or --max-kelvin"))
+    );
+}
+
+#[test]
+fn strict_maximum_falls_back_to_the_config() {
+    let config = Config { max_kelvin: Some(500.0), ..Config::default() };
+    let parsed = |line: &str| match parse_args_with(&args(line), Some(&config)) {
+        Ok(ParsedArgs::Convert(req)) => req.max_kelvin,
+        other => panic!("expected a conversion, got {other:?}"),
+    };
+
+    assert_eq!(parsed("10 --from c --to k"), Some(500.0));
+    assert_eq!(parsed("10 --from c --to k --strict"), Some(SUN_SURFACE_KELVIN));
+}
+
+#[test]
+fn strict_flags_split_off_for_value_free_modes() {
+    let list = args("--table --max-kelvin 900");
+    let (max, rest) = split_strict_flags(&list).unwrap();
+    assert_eq!(max, Some(900.0));
+    assert_eq!(rest, [&String::from("--table")]);
+
+    let (max, rest) = split_strict_flags(&[]).unwrap();
+    assert_eq!(max, None);
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn round_trip_check_warns_when_rounding_loses_too_much() {
+    let coarse = request("100 --from f --to c --decimals 0 --round-trip-check");
+    let warning = coarse.roundtrip_warning().unwrap();
+    assert!(warning.contains("loses 0.4 fahrenheit"), "{warning}");
+
+    let fine = request("100 --from f --to c --decimals 4 --round-trip-check");
+    assert_eq!(fine.roundtrip_warning(), None);
+
+    let unchecked = request("100 --from f --to c --decimals 0");
+    assert_eq!(unchecked.roundtrip_warning(), None);
+}
+
+#[test]
+fn round_trip_check_uses_the_rounding_mode() {
+    // floored to 37°C, which goes back to 98.6°F
+    let floored = request("100 --from f --to c --decimals 0 --round floor --round-trip-check");
+    let warning = floored.roundtrip_warning().unwrap();
+    assert!(warning.contains("loses 1.4 fahrenheit"), "{warning}");
+}
+
+#[test]
+fn scripted_args_report_what_is_missing() {
+    assert_eq!(parse_args(&args("--from f --to c")), Err(ArgError::MissingValue));
+    assert_eq!(parse_args(&args("98.6 --to c --from")), Err(ArgError::MissingUnit("--from")));
+    assert_eq!(parse_args(&args("98.6 --from f --to")), Err(ArgError::MissingUnit("--to")));
+    assert_eq!(
+        parse_args(&args("98.6 --from f --to q")),
+        Err(ArgError::UnknownUnit(String::from("q")))
+    );
+}
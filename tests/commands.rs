@@ -0,0 +1,51 @@
+use std::io::Cursor;
+use learning_rust::ch3_practice::convert_loop_with_commands;
+use learning_rust::commands::{Command, CommandRegistry, CommandResult};
+use learning_rust::session::Session;
+use learning_rust::Unit;
+
+// says back whatever it was given, just to see the registry reach it
+struct Echo;
+
+impl Command for Echo {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn run(&self, arg: &str) -> CommandResult {
+        CommandResult::Output(format!("echo: {arg}"))
+    }
+}
+
+#[test]
+fn dispatch_routes_to_a_registered_command() {
+    let mut registry = CommandRegistry::new();
+    registry.register(Box::new(Echo));
+
+    assert_eq!(registry.dispatch("echo hello there\n"), Some(CommandResult::Output(String::from("echo: hello there"))));
+    assert_eq!(registry.dispatch("ECHO"), Some(CommandResult::Output(String::from("echo: "))));
+    assert_eq!(registry.dispatch("98.6"), None);
+    assert_eq!(registry.dispatch("quit"), None);
+}
+
+#[test]
+fn default_registry_has_help_and_quit() {
+    let registry = CommandRegistry::default();
+    assert_eq!(registry.find("?").map(|command| command.name()), Some("help"));
+    assert_eq!(registry.dispatch("q"), Some(CommandResult::Quit));
+}
+
+#[test]
+fn loop_runs_registered_commands_before_converting() {
+    let mut registry = CommandRegistry::default();
+    registry.register(Box::new(Echo));
+    let mut session = Session::default();
+    let input = Cursor::new("echo hi\n100\nq\n".as_bytes());
+    let mut output = Vec::new();
+    convert_loop_with_commands(input, &mut output, &mut session, &registry, Unit::Celsius, Unit::Kelvin, 2)
+        .unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("echo: hi"), "{output}");
+    assert_eq!(session.history().len(), 1);
+}
@@ -0,0 +1,15 @@
+use learning_rust::common_concepts::{demo_overflow, shadowing_demo};
+
+#[test]
+fn overflow_methods_each_handle_255_plus_1() {
+    let (wrapped, saturated, checked) = demo_overflow();
+    assert_eq!(wrapped, 0);
+    assert_eq!(saturated, 255);
+    assert_eq!(checked, None);
+}
+
+#[test]
+fn shadowing_turns_three_spaces_into_a_number() {
+    // "   " shadowed into its length 3, then doubled, the inner scope's +100 doesn't stick
+    assert_eq!(shadowing_demo(), 6);
+}
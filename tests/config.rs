@@ -0,0 +1,60 @@
+use std::io::Cursor;
+use learning_rust::config::{merge, parse_config, ColorMode, Config, PartialConfig};
+use learning_rust::{TempError, Unit};
+
+#[test]
+fn reads_a_valid_config() {
+    let mut warnings = Vec::new();
+    let text = "# my defaults\nfrom = c\nto=k\n\nprecision=1\ncolor=false\nshade=blue\n";
+    let config = parse_config(Cursor::new(text), &mut warnings).unwrap();
+
+    assert_eq!(config.from, Unit::Celsius);
+    assert_eq!(config.to, Unit::Kelvin);
+    assert_eq!(config.precision, 1);
+    assert_eq!(config.color, ColorMode::Never);
+    assert_eq!(String::from_utf8(warnings).unwrap(), "warning: line 7: ignoring unknown key 'shade'\n");
+}
+
+#[test]
+fn malformed_lines_report_their_line_number() {
+    let err = parse_config(Cursor::new("from=c\nprecision\n"), Vec::new()).unwrap_err();
+    assert!(matches!(err, TempError::Line(2, _)), "{err:?}");
+
+    let err = parse_config(Cursor::new("to=x\n"), Vec::new()).unwrap_err();
+    assert_eq!(err, TempError::Line(1, Box::new(TempError::UnknownUnit(String::from("x")))));
+}
+
+#[test]
+fn max_kelvin_turns_on_strict_mode() {
+    assert_eq!(parse_config(Cursor::new(""), Vec::new()).unwrap().max_kelvin, None);
+    let config = parse_config(Cursor::new("max_kelvin = 5778\n"), Vec::new()).unwrap();
+    assert_eq!(config.max_kelvin, Some(5778.0));
+
+    let err = parse_config(Cursor::new("max_kelvin=hot\n"), Vec::new()).unwrap_err();
+    assert!(matches!(err, TempError::Line(1, _)), "{err:?}");
+}
+
+#[test]
+fn precision_above_the_max_is_an_error() {
+    assert!(parse_config(Cursor::new("precision=15\n"), Vec::new()).is_ok());
+    let err = parse_config(Cursor::new("precision=16\n"), Vec::new()).unwrap_err();
+    assert!(matches!(err, TempError::Line(1, _)), "{err:?}");
+}
+
+#[test]
+fn empty_config_is_the_defaults() {
+    let config = parse_config(Cursor::new(""), Vec::new()).unwrap();
+    assert_eq!(config, Config::default());
+    assert_eq!(config.to, Unit::Celsius);
+    assert_eq!(config.precision, 2);
+    assert_eq!(config.color, ColorMode::Auto);
+}
+
+#[test]
+fn one_key_overrides_just_that_field() {
+    let config = parse_config(Cursor::new("precision=4\n"), Vec::new()).unwrap();
+    assert_eq!(config, Config { precision: 4, ..Config::default() });
+
+    let overrides = PartialConfig { to: Some(Unit::Kelvin), ..PartialConfig::default() };
+    assert_eq!(merge(Config::default(), overrides), Config { to: Unit::Kelvin, ..Config::default() });
+}
@@ -0,0 +1,354 @@
+use std::cell::Cell;
+use std::iter;
+use learning_rust::temperature::{
+    check_range, clamp_temp, convert_all, convert_batch_in_place, convert_f32, convert_f64,
+    convert_iter, convert_saturating, describe, format_result, is_crossover, max_roundtrip_error,
+    round_nice, round_to, MAX_PRECISION, SUN_SURFACE_KELVIN,
+};
+use learning_rust::{
+    celsius_to_fahrenheit, celsius_to_kelvin, check_absolute_zero, check_kelvin, convert,
+    convert_batch, fahrenheit_to_celsius, fahrenheit_to_rankine, kelvin_to_celsius,
+    kelvin_to_rankine, rankine_to_fahrenheit, rankine_to_kelvin, round_with, RoundMode, TempError,
+    Temperature, Unit,
+};
+
+const EPSILON: f64 = 1e-9;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < EPSILON,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn fahrenheit_to_celsius_known_values() {
+    assert_close(fahrenheit_to_celsius(32.0), 0.0);
+    assert_close(fahrenheit_to_celsius(212.0), 100.0);
+    assert_close(fahrenheit_to_celsius(98.6), 37.0);
+}
+
+#[test]
+fn celsius_to_fahrenheit_known_values() {
+    assert_close(celsius_to_fahrenheit(0.0), 32.0);
+    assert_close(celsius_to_fahrenheit(100.0), 212.0);
+    assert_close(celsius_to_fahrenheit(37.0), 98.6);
+}
+
+#[test]
+fn absolute_zero() {
+    assert_close(fahrenheit_to_celsius(-459.67), -273.15);
+    assert_close(celsius_to_fahrenheit(-273.15), -459.67);
+}
+
+#[test]
+fn negatives() {
+    assert_close(fahrenheit_to_celsius(-40.0), -40.0);
+    assert_close(celsius_to_fahrenheit(-40.0), -40.0);
+    assert_close(fahrenheit_to_celsius(-4.0), -20.0);
+    assert_close(celsius_to_fahrenheit(-10.0), 14.0);
+}
+
+#[test]
+fn fahrenheit_round_trip() {
+    for degf in [-459.67, -40.0, -4.0, 0.0, 32.0, 98.6, 212.0, 451.0] {
+        assert_close(celsius_to_fahrenheit(fahrenheit_to_celsius(degf)), degf);
+    }
+}
+
+#[test]
+fn describe_buckets() {
+    assert_eq!(describe(-5.0), "freezing");
+    assert_eq!(describe(0.0), "cold");
+    assert_eq!(describe(10.0), "mild");
+    assert_eq!(describe(15.0), "mild");
+    assert_eq!(describe(20.0), "warm");
+    assert_eq!(describe(25.0), "warm");
+    assert_eq!(describe(30.0), "warm");
+    assert_eq!(describe(40.0), "hot");
+}
+
+#[test]
+fn crossover_is_exact() {
+    assert_eq!(fahrenheit_to_celsius(-40.0), -40.0);
+    assert_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+    assert!(is_crossover(-40.0));
+    assert!(!is_crossover(-39.0));
+    assert!(!is_crossover(40.0));
+}
+
+// the old i32 path overflowed on `(degf - 32) * 5` for inputs this large
+#[test]
+fn large_inputs_do_not_overflow() {
+    let degf = i32::MAX as f64;
+    let degc = fahrenheit_to_celsius(degf);
+    assert!(degc.is_finite());
+    assert!((degc - (degf - 32.0) * 5.0 / 9.0).abs() < 1e-3);
+}
+
+#[test]
+fn clamp_temp_bounds() {
+    assert_eq!(clamp_temp(-50.0, -20.0, 60.0), -20.0);
+    assert_eq!(clamp_temp(25.0, -20.0, 60.0), 25.0);
+    assert_eq!(clamp_temp(90.0, -20.0, 60.0), 60.0);
+    assert_eq!(clamp_temp(90.0, 10.0, 10.0), 10.0);
+}
+
+#[test]
+fn round_modes() {
+    assert_eq!(round_with(37.77, 1, RoundMode::Floor), 37.7);
+    assert_eq!(round_with(37.77, 1, RoundMode::Ceil), 37.8);
+    assert_eq!(round_with(37.77, 1, RoundMode::Nearest), 37.8);
+    assert_eq!(round_with(37.7777, 2, RoundMode::default()), 37.78);
+    // already on the decimal place, float error from scaling mustn't move them a step
+    assert_eq!(round_with(0.29, 2, RoundMode::Floor), 0.29);
+    assert_eq!(round_with(1.1, 2, RoundMode::Ceil), 1.1);
+}
+
+#[test]
+fn round_to_picks_the_nearest_place() {
+    assert_eq!(round_to(37.7777, 2), 37.78);
+    assert_eq!(round_to(37.7777, 1), 37.8);
+    assert_eq!(round_to(37.75, 1), 37.8);
+    assert_eq!(round_to(-37.75, 1), -37.8);
+    assert_eq!(round_to(37.25, 0), 37.0);
+    assert_eq!(round_to(37.5, 0), 38.0);
+}
+
+#[test]
+fn too_many_decimals_leaves_the_value_alone() {
+    assert_eq!(round_to(37.123456789, MAX_PRECISION), 37.123456789);
+    assert_eq!(round_to(37.123456789, 400), 37.123456789);
+    assert_eq!(round_with(37.123456789, u32::MAX, RoundMode::Floor), 37.123456789);
+    // scaling these would overflow to inf
+    assert_eq!(round_to(1e307, 2), 1e307);
+    assert_eq!(round_with(1e300, MAX_PRECISION, RoundMode::Ceil), 1e300);
+}
+
+#[test]
+fn round_trips_stay_accurate() {
+    let samples = [-459.67, -273.15, -40.0, 0.0, 32.0, 37.0, 98.6, 100.0, 212.0, 1000.0];
+    assert!(max_roundtrip_error(&samples) < 1e-9);
+}
+
+#[test]
+fn water_boils_in_historical_scales() {
+    assert_close(convert(100.0, Unit::Celsius, Unit::Newton), 33.0);
+    assert_close(convert(100.0, Unit::Celsius, Unit::Reaumur), 80.0);
+    assert_close(convert(212.0, Unit::Fahrenheit, Unit::Newton), 33.0);
+    assert_close(convert(33.0, Unit::Newton, Unit::Reaumur), 80.0);
+    assert_close(convert(80.0, Unit::Reaumur, Unit::Celsius), 100.0);
+    assert_eq!("Réaumur".parse::<Unit>(), Ok(Unit::Reaumur));
+    assert_eq!("n".parse::<Unit>(), Ok(Unit::Newton));
+}
+
+#[test]
+fn water_freezes_in_every_temperature() {
+    let celsius = Temperature::Celsius(0.0);
+    assert_close(celsius.to_fahrenheit(), 32.0);
+    assert_close(celsius.to_kelvin(), 273.15);
+
+    let fahrenheit = Temperature::Fahrenheit(32.0);
+    assert_close(fahrenheit.to_celsius(), 0.0);
+    assert_close(fahrenheit.to_kelvin(), 273.15);
+
+    let kelvin = Temperature::Kelvin(273.15);
+    assert_close(kelvin.to_celsius(), 0.0);
+    assert_close(kelvin.to_fahrenheit(), 32.0);
+}
+
+#[test]
+fn water_boils_in_every_temperature() {
+    let celsius = Temperature::Celsius(100.0);
+    assert_close(celsius.to_fahrenheit(), 212.0);
+    assert_close(celsius.to_kelvin(), 373.15);
+
+    let fahrenheit = Temperature::Fahrenheit(212.0);
+    assert_close(fahrenheit.to_celsius(), 100.0);
+    assert_close(fahrenheit.to_kelvin(), 373.15);
+
+    let kelvin = Temperature::Kelvin(373.15);
+    assert_close(kelvin.to_celsius(), 100.0);
+    assert_close(kelvin.to_fahrenheit(), 212.0);
+}
+
+#[test]
+fn water_freezes_in_all_four_units() {
+    assert_close(convert(0.0, Unit::Celsius, Unit::Fahrenheit), 32.0);
+    assert_close(convert(0.0, Unit::Celsius, Unit::Kelvin), 273.15);
+    assert_close(convert(0.0, Unit::Celsius, Unit::Rankine), 491.67);
+    assert_close(Temperature::Rankine(491.67).to_celsius(), 0.0);
+    assert_close(Temperature::Rankine(491.67).to_fahrenheit(), 32.0);
+    assert_close(Temperature::Rankine(491.67).to_kelvin(), 273.15);
+}
+
+#[test]
+fn rankine_helpers() {
+    assert_close(fahrenheit_to_rankine(32.0), 491.67);
+    assert_close(rankine_to_fahrenheit(491.67), 32.0);
+    assert_close(kelvin_to_rankine(273.15), 491.67);
+    assert_close(rankine_to_kelvin(491.67), 273.15);
+}
+
+#[test]
+fn temperatures_print_with_their_symbol() {
+    assert_eq!(Temperature::Celsius(37.0).to_string(), "37°C");
+    assert_eq!(Temperature::Fahrenheit(98.6).to_string(), "98.6°F");
+    assert_eq!(Temperature::Kelvin(273.15).to_string(), "273.15K");
+    assert_eq!(format!("{:.1}", Temperature::Celsius(36.666)), "36.7°C");
+}
+
+#[test]
+fn temperatures_compare_across_units() {
+    assert!(Temperature::Fahrenheit(212.0) == Temperature::Celsius(100.0));
+    assert!(Temperature::Kelvin(0.0) == Temperature::Rankine(0.0));
+    assert!(Temperature::Celsius(-40.0) == Temperature::Fahrenheit(-40.0));
+    assert!(Temperature::Celsius(100.0) != Temperature::Fahrenheit(100.0));
+    assert!(Temperature::Fahrenheit(100.0) < Temperature::Celsius(100.0));
+    assert!(Temperature::Kelvin(300.0) > Temperature::Celsius(0.0));
+}
+
+#[test]
+fn saturating_conversion_clamps_the_output() {
+    // -40°F is -40°C, below a 0-100°C sensor's range
+    assert_eq!(convert_saturating(-40.0, Unit::Fahrenheit, Unit::Celsius, 0.0, 100.0), 0.0);
+    // 300°F is about 148.9°C, above it
+    assert_eq!(convert_saturating(300.0, Unit::Fahrenheit, Unit::Celsius, 0.0, 100.0), 100.0);
+    // the bounds are in celsius, so 150°F (65.6°C) is inside even though 150 > 100
+    assert_close(convert_saturating(150.0, Unit::Fahrenheit, Unit::Celsius, 0.0, 100.0), 65.0 + 5.0 / 9.0);
+}
+
+#[test]
+fn round_nice_goes_to_the_nearest_half() {
+    assert_eq!(round_nice(37.77), 38.0);
+    assert_eq!(round_nice(37.2), 37.0);
+    assert_eq!(round_nice(37.3), 37.5);
+    assert_eq!(round_nice(37.25), 37.5);
+    assert_eq!(round_nice(37.75), 38.0);
+    assert_eq!(round_nice(37.5), 37.5);
+    assert_eq!(round_nice(-37.25), -37.5);
+    assert_eq!(round_nice(-0.2), 0.0);
+}
+
+#[test]
+fn format_result_always_has_the_same_decimals() {
+    assert_eq!(format_result(37.0, 2), "37.00");
+    assert_eq!(format_result(-0.0, 2), "0.00");
+    assert_eq!(format_result(-0.001, 2), "0.00");
+    assert_eq!(format_result(fahrenheit_to_celsius(0.0), 2), "-17.78");
+    assert_eq!(format_result(98.6, 0), "99");
+}
+
+#[test]
+fn f32_and_f64_differ_slightly() {
+    let narrow = convert_f32(100.1, Unit::Fahrenheit, Unit::Celsius) as f64;
+    let wide = convert_f64(100.1, Unit::Fahrenheit, Unit::Celsius);
+
+    assert_ne!(narrow, wide);
+    assert!((narrow - wide).abs() < 1e-4, "{narrow} vs {wide}");
+}
+
+#[test]
+fn convert_all_lists_every_unit_in_order() {
+    let all = convert_all(0.0, Unit::Celsius);
+    let units: Vec<Unit> = all.iter().map(|&(unit, _)| unit).collect();
+    assert_eq!(units, Unit::ALL);
+
+    assert_eq!(all[0], (Unit::Celsius, 0.0));
+    assert_close(all[1].1, 32.0);
+    assert_close(all[2].1, 273.15);
+    assert_close(all[3].1, 491.67);
+}
+
+#[test]
+fn strict_range_rejects_what_lenient_accepts() {
+    // 10,000°C is about 10,273K, hotter than the sun's surface
+    assert_eq!(check_range(10_000.0, Unit::Celsius, None), Ok(10_000.0));
+    assert_eq!(
+        check_range(10_000.0, Unit::Celsius, Some(SUN_SURFACE_KELVIN)),
+        Err(TempError::AboveMaximum(SUN_SURFACE_KELVIN))
+    );
+    assert_eq!(check_range(98.6, Unit::Fahrenheit, Some(SUN_SURFACE_KELVIN)), Ok(98.6));
+    assert!(check_range(-500.0, Unit::Fahrenheit, None).is_err());
+}
+
+#[test]
+fn convert_iter_only_converts_what_is_taken() {
+    let pulled = Cell::new(0);
+    let source = iter::repeat(-40.0).inspect(|_| pulled.set(pulled.get() + 1));
+
+    let results: Vec<f64> = convert_iter(source, Unit::Celsius, Unit::Fahrenheit).take(5).collect();
+
+    assert_eq!(results, [-40.0; 5]);
+    assert_eq!(pulled.get(), 5);
+}
+
+#[test]
+fn in_place_batch_matches_the_allocating_one() {
+    let original = [-459.67, -40.0, 0.0, 32.0, 98.6, 212.0, 1000.0];
+    let mut values = original;
+    convert_batch_in_place(&mut values, Unit::Fahrenheit, Unit::Kelvin);
+
+    assert_eq!(values.to_vec(), convert_batch(&original, Unit::Fahrenheit, Unit::Kelvin));
+}
+
+#[test]
+fn converts_a_batch_of_fahrenheit() {
+    let celsius = convert_batch(&[32.0, 212.0, 98.6], Unit::Fahrenheit, Unit::Celsius);
+
+    assert_eq!(celsius.len(), 3);
+    assert_close(celsius[0], 0.0);
+    assert_close(celsius[1], 100.0);
+    assert_close(celsius[2], 37.0);
+}
+
+#[test]
+fn kelvin_known_values() {
+    assert_close(celsius_to_kelvin(0.0), 273.15);
+    assert_close(kelvin_to_celsius(273.15), 0.0);
+    assert_close(convert(212.0, Unit::Fahrenheit, Unit::Kelvin), 373.15);
+}
+
+#[test]
+fn negative_kelvin_is_rejected() {
+    assert_eq!(check_kelvin(-1.0), Err(TempError::BelowAbsoluteZero));
+    assert_eq!(check_kelvin(0.0), Ok(0.0));
+}
+
+#[test]
+fn below_absolute_zero_is_rejected() {
+    assert_eq!(check_absolute_zero(-300.0, Unit::Celsius), Err(TempError::BelowAbsoluteZero));
+    assert_eq!(check_absolute_zero(-500.0, Unit::Fahrenheit), Err(TempError::BelowAbsoluteZero));
+    assert_eq!(check_absolute_zero(-273.15, Unit::Celsius), Ok(-273.15));
+}
+
+#[test]
+fn parses_every_unit_spelling() {
+    assert_eq!("c".parse::<Unit>(), Ok(Unit::Celsius));
+    assert_eq!("celsius".parse::<Unit>(), Ok(Unit::Celsius));
+    assert_eq!("f".parse::<Unit>(), Ok(Unit::Fahrenheit));
+    assert_eq!("fahrenheit".parse::<Unit>(), Ok(Unit::Fahrenheit));
+    assert_eq!("k".parse::<Unit>(), Ok(Unit::Kelvin));
+    assert_eq!("kelvin".parse::<Unit>(), Ok(Unit::Kelvin));
+    assert_eq!("r".parse::<Unit>(), Ok(Unit::Rankine));
+    assert_eq!("rankine".parse::<Unit>(), Ok(Unit::Rankine));
+    assert_eq!("n".parse::<Unit>(), Ok(Unit::Newton));
+    assert_eq!("newton".parse::<Unit>(), Ok(Unit::Newton));
+    assert_eq!("re".parse::<Unit>(), Ok(Unit::Reaumur));
+    assert_eq!("reaumur".parse::<Unit>(), Ok(Unit::Reaumur));
+    assert_eq!("réaumur".parse::<Unit>(), Ok(Unit::Reaumur));
+}
+
+#[test]
+fn unit_spellings_ignore_case_and_spaces() {
+    assert_eq!("C".parse::<Unit>(), Ok(Unit::Celsius));
+    assert_eq!("Fahrenheit".parse::<Unit>(), Ok(Unit::Fahrenheit));
+    assert_eq!(" KELVIN ".parse::<Unit>(), Ok(Unit::Kelvin));
+    assert_eq!("RE".parse::<Unit>(), Ok(Unit::Reaumur));
+}
+
+#[test]
+fn unknown_unit_is_an_error() {
+    assert_eq!("x".parse::<Unit>(), Err(TempError::UnknownUnit("x".to_string())));
+    assert_eq!("".parse::<Unit>(), Err(TempError::UnknownUnit(String::new())));
+}
@@ -0,0 +1,30 @@
+use learning_rust::convertible::convert_within;
+use learning_rust::length::LengthUnit;
+use learning_rust::mass::MassUnit;
+use learning_rust::Unit;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "expected {expected}, got {actual}");
+}
+
+#[test]
+fn temperatures_need_the_offset() {
+    assert_close(convert_within(212.0, Unit::Fahrenheit, Unit::Celsius), 100.0);
+    assert_close(convert_within(0.0, Unit::Celsius, Unit::Fahrenheit), 32.0);
+    assert_close(convert_within(-40.0, Unit::Fahrenheit, Unit::Celsius), -40.0);
+    assert_close(convert_within(0.0, Unit::Kelvin, Unit::Rankine), 0.0);
+    assert_close(convert_within(33.0, Unit::Newton, Unit::Kelvin), 373.15);
+    assert_close(convert_within(80.0, Unit::Reaumur, Unit::Fahrenheit), 212.0);
+}
+
+#[test]
+fn lengths_only_scale() {
+    assert_close(convert_within(1.0, LengthUnit::Miles, LengthUnit::Meters), 1609.344);
+    assert_close(convert_within(12.0, LengthUnit::Inches, LengthUnit::Feet), 1.0);
+}
+
+#[test]
+fn masses_only_scale() {
+    assert_close(convert_within(1.0, MassUnit::Kilograms, MassUnit::Grams), 1000.0);
+    assert_close(convert_within(16.0, MassUnit::Ounces, MassUnit::Pounds), 1.0);
+}
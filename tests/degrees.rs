@@ -0,0 +1,28 @@
+use learning_rust::convertible::convert;
+use learning_rust::degrees::{Celsius, Fahrenheit};
+
+#[test]
+fn adds_and_subtracts_within_a_unit() {
+    assert_eq!(Celsius(10.0) + Celsius(5.0), Celsius(15.0));
+    assert_eq!(Celsius(10.0) - Celsius(5.0), Celsius(5.0));
+    assert_eq!(Fahrenheit(32.0) + Fahrenheit(180.0), Fahrenheit(212.0));
+}
+
+#[test]
+fn converts_between_units() {
+    assert_eq!(Celsius::from(Fahrenheit(212.0)), Celsius(100.0));
+    let f: Fahrenheit = Celsius(-40.0).into();
+    assert_eq!(f, Fahrenheit(-40.0));
+}
+
+#[test]
+fn converts_through_the_generic_path() {
+    let c: Celsius = convert(Fahrenheit(212.0));
+    assert_eq!(c, Celsius(100.0));
+
+    let f: Fahrenheit = convert(Celsius(37.0));
+    assert!((f.0 - 98.6).abs() < 1e-9);
+
+    let same: Celsius = convert(Celsius(21.5));
+    assert_eq!(same, Celsius(21.5));
+}
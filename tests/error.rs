@@ -0,0 +1,45 @@
+use learning_rust::error::error_exit_code;
+use learning_rust::TempError;
+
+#[test]
+fn parse_errors_exit_with_2() {
+    assert_eq!(error_exit_code(&TempError::ParseFailed(String::from("'abc'"))), 2);
+    assert_eq!(error_exit_code(&TempError::NotFinite), 2);
+}
+
+#[test]
+fn range_errors_exit_with_3() {
+    assert_eq!(error_exit_code(&TempError::BelowAbsoluteZero), 3);
+    let on_line = TempError::Line(4, Box::new(TempError::BelowAbsoluteZero));
+    assert_eq!(error_exit_code(&on_line), 3);
+}
+
+#[test]
+fn errors_read_like_sentences() {
+    assert_eq!(
+        TempError::ParseFailed(String::from("'abc'")).to_string(),
+        "not a valid temperature: 'abc'"
+    );
+    assert_eq!(TempError::NotFinite.to_string(), "temperatures have to be a finite number");
+    assert_eq!(
+        TempError::BelowAbsoluteZero.to_string(),
+        "that temperature is below absolute zero"
+    );
+}
+
+fn parse_with_question_mark(input: &str) -> Result<f64, TempError> {
+    let value: f64 = input.parse()?;
+    Ok(value)
+}
+
+#[test]
+fn parse_float_errors_convert_with_question_mark() {
+    assert_eq!(parse_with_question_mark("12.5"), Ok(12.5));
+    assert!(matches!(parse_with_question_mark("abc"), Err(TempError::ParseFailed(_))));
+}
+
+#[test]
+fn temp_errors_work_as_std_errors() {
+    let err: Box<dyn std::error::Error> = Box::new(TempError::NotFinite);
+    assert_eq!(err.to_string(), "temperatures have to be a finite number");
+}
@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+use learning_rust::ch3_practice::{fibonacci, fizzbuzz, Fib, FibCache, FibError};
+use learning_rust::guess::compare_guess;
+
+#[test]
+fn fibonacci_first_values() {
+    let values: Vec<u64> = (0..10).map(|n| fibonacci(n).unwrap()).collect();
+    assert_eq!(values, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+}
+
+#[test]
+fn fibonacci_90_fits_in_a_u64() {
+    assert_eq!(fibonacci(90), Ok(2_880_067_194_370_816_120));
+}
+
+#[test]
+fn fibonacci_overflows_after_93() {
+    assert_eq!(fibonacci(93), Ok(12_200_160_415_121_876_738));
+    assert_eq!(fibonacci(94), Err(FibError::Overflow));
+}
+
+#[test]
+fn fib_iterator_first_ten() {
+    let values: Vec<u64> = Fib::new().take(10).collect();
+    assert_eq!(values, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+}
+
+#[test]
+fn fib_iterator_stops_before_overflow() {
+    // fib(0) through fib(93)
+    assert_eq!(Fib::new().count(), 94);
+    assert_eq!(Fib::new().last(), Some(12_200_160_415_121_876_738));
+}
+
+#[test]
+fn fib_cache_reuses_entries() {
+    let mut cache = FibCache::new();
+    assert_eq!(cache.get(20), Ok(6765));
+    assert_eq!(cache.get(20), Ok(6765));
+
+    assert_eq!(cache.cached().len(), 21);
+    assert_eq!(cache.cached()[10], 55);
+}
+
+#[test]
+fn fib_cache_overflows_after_93() {
+    let mut cache = FibCache::new();
+    assert_eq!(cache.get(94), Err(FibError::Overflow));
+    assert_eq!(cache.cached().len(), 94);
+    assert_eq!(cache.get(93), Ok(12_200_160_415_121_876_738));
+}
+
+#[test]
+fn fizzbuzz_values() {
+    let lines = fizzbuzz(15);
+    assert_eq!(lines.len(), 15);
+    assert_eq!(lines[2], "Fizz");
+    assert_eq!(lines[4], "Buzz");
+    assert_eq!(lines[6], "7");
+    assert_eq!(lines[14], "FizzBuzz");
+    assert!(fizzbuzz(0).is_empty());
+}
+
+#[test]
+fn guesses_compare_against_the_secret() {
+    assert_eq!(compare_guess(10, 50), Ordering::Less);
+    assert_eq!(compare_guess(99, 50), Ordering::Greater);
+    assert_eq!(compare_guess(50, 50), Ordering::Equal);
+}
@@ -0,0 +1,239 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::io::{self, Cursor};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use learning_rust::file::{
+    convert_file, convert_file_to_csv, convert_file_with_progress, convert_lines, convert_lines_table,
+    convert_lines_with, summary_line,
+};
+use learning_rust::temperature::SUN_SURFACE_KELVIN;
+use learning_rust::session::{log_conversion, ConvertRecord};
+use learning_rust::{fahrenheit_to_celsius, round_to, TempError, Unit};
+
+// writes the contents to a file in the system temp dir, unique per test
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("learning_rust_{}_{name}", std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn csv_has_header_and_rounded_rows() {
+    let path = temp_file("csv.txt", "32\n212\n100\n");
+    let csv = convert_file_to_csv(&path, Unit::Fahrenheit, Unit::Celsius, 2, None).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(csv, "input,result\n32,0.00\n212,100.00\n100,37.78\n");
+}
+
+#[test]
+fn csv_uses_the_given_precision() {
+    let path = temp_file("csv_precision.txt", "100\n98.6\n");
+    let whole = convert_file_to_csv(&path, Unit::Fahrenheit, Unit::Celsius, 0, None).unwrap();
+    let four = convert_file_to_csv(&path, Unit::Fahrenheit, Unit::Celsius, 4, None).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(whole, "input,result\n100,38\n98.6,37\n");
+    assert_eq!(four, "input,result\n100,37.7778\n98.6,37.0000\n");
+}
+
+#[test]
+fn strict_csv_rejects_impossible_heat() {
+    let path = temp_file("csv_strict.txt", "100\n10000\n");
+    let csv = convert_file_to_csv(&path, Unit::Celsius, Unit::Kelvin, 2, Some(SUN_SURFACE_KELVIN));
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(csv, Err(TempError::Line(2, Box::new(TempError::AboveMaximum(SUN_SURFACE_KELVIN)))));
+}
+
+#[test]
+fn converts_every_non_empty_line() {
+    let path = temp_file("mixed.txt", "32\n\n212\n  98.6  \n-40\n");
+    let values = convert_file(&path, Unit::Fahrenheit, Unit::Celsius).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let rounded: Vec<f64> = values.iter().map(|&c| round_to(c, 2)).collect();
+    assert_eq!(rounded, [0.0, 100.0, 37.0, -40.0]);
+}
+
+#[test]
+fn bad_lines_report_their_line_number() {
+    let path = temp_file("bad.txt", "32\n\nabc\n");
+    let err = convert_file(&path, Unit::Fahrenheit, Unit::Celsius).unwrap_err();
+    fs::remove_file(&path).unwrap();
+
+    assert!(matches!(err, TempError::Line(3, _)), "{err:?}");
+}
+
+#[test]
+fn piped_lines_are_converted_one_per_line() {
+    let input = Cursor::new("32\n\nabc\n212\n".as_bytes());
+    let mut output = Vec::new();
+    let mut errors = Vec::new();
+    let result = convert_lines(input, &mut output, &mut errors, Unit::Fahrenheit, Unit::Celsius);
+
+    // every line is still converted, but the bad one is reported back for the exit code
+    assert_eq!(result, Err(TempError::Line(3, Box::new(TempError::ParseFailed(String::from("'abc'"))))));
+    assert_eq!(String::from_utf8(output).unwrap(), "0.00\n100.00\n");
+    assert_eq!(String::from_utf8(errors).unwrap(), "line 3: not a valid temperature: 'abc'\n");
+}
+
+#[test]
+fn empty_piped_input_is_fine() {
+    let mut output = Vec::new();
+    convert_lines(Cursor::new(&b""[..]), &mut output, Vec::new(), Unit::Fahrenheit, Unit::Celsius)
+        .unwrap();
+    assert!(output.is_empty());
+}
+
+#[test]
+fn many_piped_lines_convert_in_order() {
+    // alternating trailing spaces and windows line endings, with a longer line first so a
+    // reused buffer that wasn't cleared would show up as garbage on the next line
+    let mut input = String::from("212.000000   \n");
+    let mut expected = String::from("100.00\n");
+    for c in 0..1000 {
+        let ending = if c % 2 == 0 { "  \n" } else { "\r\n" };
+        input.push_str(&format!("{}{ending}", c * 9 / 5 + 32));
+        expected.push_str(&format!("{:.2}\n", fahrenheit_to_celsius((c * 9 / 5 + 32) as f64)));
+    }
+
+    let mut output = Vec::new();
+    let mut errors = Vec::new();
+    convert_lines(Cursor::new(input), &mut output, &mut errors, Unit::Fahrenheit, Unit::Celsius).unwrap();
+
+    assert_eq!(String::from_utf8(output).unwrap(), expected);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn conversions_are_appended_to_the_log() {
+    let path = env::temp_dir().join(format!("learning_rust_{}_conversions.log", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let boiling =
+        ConvertRecord { input: 212.0, from: Unit::Fahrenheit, to: Unit::Celsius, result: 100.0, precision: 2 };
+    let freezing =
+        ConvertRecord { input: 0.0, from: Unit::Celsius, to: Unit::Fahrenheit, result: 32.0, precision: 1 };
+    log_conversion(&path, &boiling).unwrap();
+    log_conversion(&path, &freezing).unwrap();
+    let log = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with(" 212°F -> 100.00°C"), "{}", lines[0]);
+    assert!(lines[1].ends_with(" 0°C -> 32.0°F"), "{}", lines[1]);
+}
+
+#[test]
+fn logged_results_just_under_zero_have_no_minus() {
+    let record =
+        ConvertRecord { input: 31.999, from: Unit::Fahrenheit, to: Unit::Celsius, result: -0.0, precision: 2 };
+    assert_eq!(record.to_string(), "31.999°F -> 0.00°C");
+}
+
+#[test]
+fn first_bad_piped_line_is_the_error() {
+    let input = Cursor::new("-9999\n32\nabc\n".as_bytes());
+    let result = convert_lines(input, Vec::new(), Vec::new(), Unit::Fahrenheit, Unit::Celsius);
+    assert_eq!(result, Err(TempError::Line(1, Box::new(TempError::BelowAbsoluteZero))));
+
+    let input = Cursor::new("32\n212\n".as_bytes());
+    assert_eq!(convert_lines(input, Vec::new(), Vec::new(), Unit::Fahrenheit, Unit::Celsius), Ok(()));
+}
+
+#[test]
+fn strict_piped_lines_reject_impossible_heat() {
+    let input = Cursor::new("100\n10000\n".as_bytes());
+    let mut output = Vec::new();
+    let mut errors = Vec::new();
+    let result =
+        convert_lines_with(input, &mut output, &mut errors, Unit::Celsius, Unit::Kelvin, Some(SUN_SURFACE_KELVIN));
+
+    assert_eq!(result, Err(TempError::Line(2, Box::new(TempError::AboveMaximum(SUN_SURFACE_KELVIN)))));
+    assert_eq!(String::from_utf8(output).unwrap(), "373.15\n");
+    assert!(String::from_utf8(errors).unwrap().starts_with("line 2: that temperature is above 5778K"));
+}
+
+#[test]
+fn big_files_report_progress() {
+    let contents: String = (0..25_000).map(|i| format!("{}\n", i % 200)).collect();
+    let path = temp_file("progress.txt", &contents);
+    let mut progress = Vec::new();
+    let results = convert_file_with_progress(&path, Unit::Celsius, Unit::Kelvin, None, &mut progress, &AtomicBool::new(false))
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(results.len(), 25_000);
+    assert_eq!(String::from_utf8(progress).unwrap(), "processed 10000\nprocessed 20000\n");
+}
+
+#[test]
+fn cancelled_file_stops_after_one_line() {
+    let path = temp_file("cancel.txt", "0\n100\n200\n");
+    let cancel = Arc::new(AtomicBool::new(true));
+    let results = convert_file_with_progress(&path, Unit::Celsius, Unit::Kelvin, None, io::sink(), &cancel).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(results, vec![273.15]);
+}
+
+#[test]
+fn summary_of_a_converted_file() {
+    let path = temp_file("summary.txt", "32\n212\n50\n");
+    let results = convert_file(&path, Unit::Fahrenheit, Unit::Celsius).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(summary_line(&results).unwrap(), "summary: mean 36.67, min 0.00, max 100.00");
+    assert_eq!(summary_line(&[]), None);
+    assert_eq!(summary_line(&[-0.0001]).unwrap(), "summary: mean 0.00, min 0.00, max 0.00");
+}
+
+#[test]
+fn table_columns_are_padded_to_the_widest_value() {
+    let input = Cursor::new("0\n-40\nabc\n1000.5\n".as_bytes());
+    let mut output = Vec::new();
+    let mut errors = Vec::new();
+    let result = convert_lines_table(input, &mut output, &mut errors, Unit::Celsius, Unit::Fahrenheit, None);
+    assert!(matches!(result, Err(TempError::Line(3, _))), "{result:?}");
+
+    let expected = [
+        "   Input |    Result",
+        "---------+----------",
+        "     0°C |   32.00°F",
+        "   -40°C |  -40.00°F",
+        "1000.5°C | 1832.90°F",
+    ];
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+    assert!(String::from_utf8(errors).unwrap().starts_with("line 3: "));
+}
+
+#[test]
+fn strict_files_reject_impossible_heat() {
+    let path = temp_file("strict.txt", "100\n10000\n");
+    let never = AtomicBool::new(false);
+    let lenient = convert_file_with_progress(&path, Unit::Celsius, Unit::Kelvin, None, io::sink(), &never);
+    let strict =
+        convert_file_with_progress(&path, Unit::Celsius, Unit::Kelvin, Some(SUN_SURFACE_KELVIN), io::sink(), &never);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(lenient.unwrap().len(), 2);
+    assert_eq!(strict, Err(TempError::Line(2, Box::new(TempError::AboveMaximum(SUN_SURFACE_KELVIN)))));
+}
+
+#[test]
+fn strict_tables_leave_out_impossible_heat() {
+    let input = Cursor::new("100\n10000\n".as_bytes());
+    let mut output = Vec::new();
+    let mut errors = Vec::new();
+    let result =
+        convert_lines_table(input, &mut output, &mut errors, Unit::Celsius, Unit::Kelvin, Some(SUN_SURFACE_KELVIN));
+    assert!(matches!(result, Err(TempError::Line(2, _))), "{result:?}");
+
+    assert_eq!(String::from_utf8(output).unwrap().lines().count(), 3);
+    assert!(String::from_utf8(errors).unwrap().starts_with("line 2: that temperature is above 5778K"));
+}
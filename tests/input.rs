@@ -0,0 +1,13 @@
+use learning_rust::input::parse_trimmed;
+
+#[test]
+fn parse_trimmed_strips_whitespace() {
+    assert_eq!(parse_trimmed::<f64>(" 98.6\n"), Some(98.6));
+    assert_eq!(parse_trimmed::<u32>("\t42 "), Some(42));
+}
+
+#[test]
+fn parse_trimmed_rejects_garbage() {
+    assert_eq!(parse_trimmed::<u32>("forty two"), None);
+    assert_eq!(parse_trimmed::<u32>("-1"), None);
+}
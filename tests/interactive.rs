@@ -0,0 +1,146 @@
+use std::io::Cursor;
+use learning_rust::ch3_practice::{convert_loop, help_text, run_convert};
+use learning_rust::session::Session;
+use learning_rust::temperature::SUN_SURFACE_KELVIN;
+use learning_rust::Unit;
+
+fn run(input: &str) -> String {
+    let mut output = Vec::new();
+    run_convert(Cursor::new(input.as_bytes()), &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn converts_piped_fahrenheit() {
+    let output = run("98.6\n");
+    assert!(output.contains("98.6°F -> 37.00°C (hot)"), "{output}");
+}
+
+#[test]
+fn fractional_and_negative_fahrenheit_keep_their_decimals() {
+    let output = run("98\n98.6\n-40\n");
+    assert!(output.contains("98°F -> 36.67°C"), "{output}");
+    assert!(output.contains("98.6°F -> 37.00°C"), "{output}");
+    assert!(output.contains("-40°F -> -40.00°C"), "{output}");
+}
+
+// the loop temp_convert_c_to_f runs on the terminal
+#[test]
+fn converts_celsius_to_fahrenheit() {
+    let mut session = Session::default();
+    let input = Cursor::new("100\n0\n".as_bytes());
+    let mut output = Vec::new();
+    convert_loop(input, &mut output, &mut session, Unit::Celsius, Unit::Fahrenheit, 2).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("100°C -> 212.00°F"), "{output}");
+    assert!(output.contains("0°C -> 32.00°F"), "{output}");
+}
+
+#[test]
+fn history_keeps_conversions_in_order() {
+    let mut session = Session::default();
+    let input = Cursor::new("32\n212\n98.6\nhistory\n".as_bytes());
+    let mut output = Vec::new();
+    convert_loop(input, &mut output, &mut session, Unit::Fahrenheit, Unit::Celsius, 2).unwrap();
+
+    let inputs: Vec<f64> = session.history().iter().map(|record| record.input).collect();
+    assert_eq!(inputs, [32.0, 212.0, 98.6]);
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("3. 98.6°F -> 37.00°C"), "{output}");
+}
+
+#[test]
+fn results_just_under_zero_print_without_a_minus() {
+    let output = run("31.999\nlast\nhistory\n");
+    assert!(output.contains("31.999°F -> 0.00°C (freezing)"), "{output}");
+    assert!(output.contains("31.999°F -> 0.00°C\nInput"), "{output}");
+    assert!(output.contains("1. 31.999°F -> 0.00°C"), "{output}");
+    assert!(!output.contains("-0"), "{output}");
+}
+
+#[test]
+fn history_is_capped() {
+    let mut session = Session::new(2);
+    let input = Cursor::new("1\n2\n3\n".as_bytes());
+    convert_loop(input, Vec::new(), &mut session, Unit::Celsius, Unit::Kelvin, 2).unwrap();
+
+    let inputs: Vec<f64> = session.history().iter().map(|record| record.input).collect();
+    assert_eq!(inputs, [2.0, 3.0]);
+}
+
+#[test]
+fn last_and_repeat_without_history() {
+    let output = run("last\nrepeat\nq\n");
+    assert!(output.contains("No conversions yet\n"), "{output}");
+    assert!(output.contains("nothing to repeat"), "{output}");
+}
+
+#[test]
+fn repeat_reuses_the_last_direction() {
+    let output = run("212\nrepeat\n32\nlast\n");
+    assert!(output.contains("32°F -> 0.00°C (cold)"), "{output}");
+    // `last` prints the record without the feels-like description
+    assert!(output.contains("32°F -> 0.00°C\nInput"), "{output}");
+}
+
+#[test]
+fn many_lines_all_get_converted() {
+    let mut session = Session::default();
+    let input: String = (0..500).map(|c| format!("{c}   \n")).collect();
+    let mut output = Vec::new();
+    convert_loop(Cursor::new(input), &mut output, &mut session, Unit::Celsius, Unit::Kelvin, 2).unwrap();
+
+    assert_eq!(session.history().len(), 100);
+    assert_eq!(session.history().last().unwrap().result, 772.15);
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches(" -> ").count(), 500);
+    assert!(!output.contains("try again"), "{output}");
+}
+
+#[test]
+fn blank_lines_just_prompt_again() {
+    let mut session = Session::default();
+    let input = Cursor::new("\n   \n100\n".as_bytes());
+    let mut output = Vec::new();
+    convert_loop(input, &mut output, &mut session, Unit::Celsius, Unit::Fahrenheit, 2).unwrap();
+
+    assert_eq!(session.history().len(), 1);
+    let output = String::from_utf8(output).unwrap();
+    assert!(!output.contains("try again"), "{output}");
+    assert_eq!(output.matches(" -> ").count(), 1);
+}
+
+#[test]
+fn help_lists_the_commands_then_quit_exits() {
+    let output = run("help\nquit\n98.6\n");
+    assert!(output.contains(help_text()), "{output}");
+    assert!(!output.contains(" -> "), "{output}");
+    assert_eq!(output.matches("commands:").count(), 1);
+}
+
+#[test]
+fn comma_separated_lists_convert_each_value() {
+    let mut session = Session::default();
+    let input = Cursor::new("32, abc, 212,, 98.6\n".as_bytes());
+    let mut output = Vec::new();
+    convert_loop(input, &mut output, &mut session, Unit::Fahrenheit, Unit::Celsius, 2).unwrap();
+
+    let results: Vec<f64> = session.history().iter().map(|record| record.result).collect();
+    assert_eq!(results, [0.0, 100.0, 37.0]);
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("skipping 'abc': not a valid temperature"), "{output}");
+}
+
+#[test]
+fn strict_sessions_reject_impossible_heat() {
+    let mut session = Session::default().strict(SUN_SURFACE_KELVIN);
+    let input = Cursor::new("10000\n25\n".as_bytes());
+    let mut output = Vec::new();
+    convert_loop(input, &mut output, &mut session, Unit::Celsius, Unit::Kelvin, 2).unwrap();
+
+    assert_eq!(session.history().len(), 1);
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("above 5778K"), "{output}");
+}
@@ -0,0 +1,26 @@
+use learning_rust::length::{convert_length, LengthUnit};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "expected {expected}, got {actual}");
+}
+
+#[test]
+fn a_mile_in_meters() {
+    assert_close(convert_length(1.0, LengthUnit::Miles, LengthUnit::Meters), 1609.344);
+    assert_close(convert_length(1609.344, LengthUnit::Meters, LengthUnit::Miles), 1.0);
+    assert_close(convert_length(1.0, LengthUnit::Miles, LengthUnit::Feet), 5280.0);
+}
+
+#[test]
+fn meters_convert_to_inches_and_feet() {
+    assert_close(convert_length(1.0, LengthUnit::Meters, LengthUnit::Inches), 100.0 / 2.54);
+    assert_close(convert_length(0.001, LengthUnit::Kilometers, LengthUnit::Meters), 1.0);
+    assert_close(convert_length(1.0, LengthUnit::Meters, LengthUnit::Feet), 3.280839895013123);
+}
+
+#[test]
+fn parses_unit_names() {
+    assert_eq!("FT".parse(), Ok(LengthUnit::Feet));
+    assert_eq!("kilometres".parse(), Ok(LengthUnit::Kilometers));
+    assert!("furlong".parse::<LengthUnit>().is_err());
+}
@@ -0,0 +1,41 @@
+use learning_rust::mass::{convert_mass, mass_line, MassUnit};
+use learning_rust::TempError;
+
+#[test]
+fn a_pound_in_grams() {
+    let grams = convert_mass(1.0, MassUnit::Pounds, MassUnit::Grams).unwrap();
+    assert!((grams - 453.592).abs() < 1e-3, "{grams}");
+
+    let ounces = convert_mass(1.0, MassUnit::Pounds, MassUnit::Ounces).unwrap();
+    assert!((ounces - 16.0).abs() < 1e-9, "{ounces}");
+}
+
+#[test]
+fn a_kilogram_in_grams() {
+    assert_eq!(convert_mass(1.0, MassUnit::Kilograms, MassUnit::Grams), Ok(1000.0));
+    assert_eq!(convert_mass(0.0, MassUnit::Kilograms, MassUnit::Pounds), Ok(0.0));
+}
+
+#[test]
+fn mass_lines_have_fixed_decimals() {
+    assert_eq!(mass_line(1.0, MassUnit::Pounds, MassUnit::Grams, 453.59237), "1 pounds = 453.59 grams");
+    assert_eq!(mass_line(0.0, MassUnit::Grams, MassUnit::Kilograms, -0.0), "0 grams = 0.00 kilograms");
+}
+
+#[test]
+fn negative_masses_are_rejected() {
+    assert_eq!(
+        convert_mass(-1.0, MassUnit::Kilograms, MassUnit::Grams),
+        Err(TempError::NegativeMass(-1.0))
+    );
+}
+
+#[test]
+fn infinite_and_nan_masses_are_rejected() {
+    assert_eq!(convert_mass(f64::NAN, MassUnit::Kilograms, MassUnit::Grams), Err(TempError::NotFinite));
+    assert_eq!(convert_mass(f64::INFINITY, MassUnit::Pounds, MassUnit::Grams), Err(TempError::NotFinite));
+    assert_eq!(
+        convert_mass(f64::NEG_INFINITY, MassUnit::Pounds, MassUnit::Grams),
+        Err(TempError::NotFinite)
+    );
+}
@@ -0,0 +1,36 @@
+use learning_rust::ownership::{
+    append_world, calculate_length, calculate_length_moved, write_ownership_demo,
+};
+
+#[test]
+fn demo_explains_moves_and_clones() {
+    let mut output = Vec::new();
+    write_ownership_demo(&mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.contains("moved"), "{output}");
+    assert!(output.contains("cloned"), "{output}");
+    assert!(output.contains("s is now \"hello, world\""), "{output}");
+}
+
+#[test]
+fn moved_version_hands_the_string_back() {
+    let s = String::from("hello");
+    let (s, len) = calculate_length_moved(s);
+    assert_eq!((s.as_str(), len), ("hello", 5));
+}
+
+#[test]
+fn borrowed_version_leaves_the_original_usable() {
+    let s = String::from("hello, world");
+    let len = calculate_length(&s);
+    assert_eq!(len, 12);
+    assert_eq!(s, "hello, world");
+}
+
+#[test]
+fn append_world_changes_the_callers_string() {
+    let mut s = String::from("hello");
+    append_world(&mut s);
+    assert_eq!(s, "hello, world");
+}
@@ -0,0 +1,104 @@
+use learning_rust::temperature::{parse_temperature_locale, parse_temperature_tagged};
+use learning_rust::{parse_temperature, TempError, Temperature, Unit};
+
+#[test]
+fn accepts_exponent_notation() {
+    assert_eq!(parse_temperature("6.02e1"), Ok(60.2));
+}
+
+#[test]
+fn trims_surrounding_whitespace() {
+    assert_eq!(parse_temperature(" 100 "), Ok(100.0));
+    assert_eq!(parse_temperature("\t212\n"), Ok(212.0));
+}
+
+#[test]
+fn rejects_infinity_and_nan() {
+    assert_eq!(parse_temperature("inf"), Err(TempError::NotFinite));
+    assert_eq!(parse_temperature("-inf"), Err(TempError::NotFinite));
+    assert_eq!(parse_temperature("infinity"), Err(TempError::NotFinite));
+    assert_eq!(parse_temperature("nan"), Err(TempError::NotFinite));
+    assert_eq!(parse_temperature("NaN"), Err(TempError::NotFinite));
+}
+
+#[test]
+fn rejects_underscores() {
+    assert!(matches!(parse_temperature("1_000"), Err(TempError::ParseFailed(_))));
+}
+
+#[test]
+fn accepts_fractions() {
+    assert!((parse_temperature("98 3/5").unwrap() - 98.6).abs() < 1e-9);
+    assert_eq!(parse_temperature("3/5"), Ok(0.6));
+    assert!((parse_temperature("-98 3/5").unwrap() + 98.6).abs() < 1e-9);
+    assert_eq!(parse_temperature("-3/5"), Ok(-0.6));
+    assert_eq!(parse_temperature("+3/5"), Ok(0.6));
+    assert!(parse_temperature("98 -3/5").is_err());
+    assert!(parse_temperature("--3/5").is_err());
+}
+
+#[test]
+fn rejects_zero_denominators() {
+    assert_eq!(parse_temperature("1/0"), Err(TempError::DivisionByZero));
+}
+
+#[test]
+fn decimal_comma() {
+    assert_eq!(parse_temperature_locale("98,6", true), Ok(98.6));
+    assert_eq!(parse_temperature_locale("98.6", true), Ok(98.6));
+    assert!(parse_temperature_locale("98,6", false).is_err());
+}
+
+#[test]
+fn both_separators_are_ambiguous() {
+    assert_eq!(
+        parse_temperature_locale("1.000,5", true),
+        Err(TempError::AmbiguousSeparator(String::from("1.000,5")))
+    );
+}
+
+#[test]
+fn tagged_temperatures_carry_their_unit() {
+    assert_eq!(parse_temperature_tagged("98.6F"), Ok((98.6, Unit::Fahrenheit)));
+    assert_eq!(parse_temperature_tagged("37c"), Ok((37.0, Unit::Celsius)));
+    assert_eq!(parse_temperature_tagged(" -40 °F "), Ok((-40.0, Unit::Fahrenheit)));
+    assert_eq!(parse_temperature_tagged("80Re"), Ok((80.0, Unit::Reaumur)));
+    assert_eq!(parse_temperature_tagged("100"), Err(TempError::MissingUnit(String::from("100"))));
+    assert_eq!(parse_temperature_tagged("100X"), Err(TempError::UnknownUnit(String::from("X"))));
+}
+
+#[test]
+fn tagged_strings_convert_into_temperatures() {
+    // Temperature's == compares across units, so the variant is checked with matches!
+    assert!(matches!(Temperature::try_from("100C"), Ok(Temperature::Celsius(c)) if c == 100.0));
+    assert!(matches!(Temperature::try_from("212F"), Ok(Temperature::Fahrenheit(f)) if f == 212.0));
+    assert!(matches!(Temperature::try_from("273.15K"), Ok(Temperature::Kelvin(k)) if k == 273.15));
+    assert!(matches!(Temperature::try_from("491.67R"), Ok(Temperature::Rankine(r)) if r == 491.67));
+    assert!(matches!(Temperature::try_from("33N"), Ok(Temperature::Newton(n)) if n == 33.0));
+    assert!(matches!(Temperature::try_from("80Re"), Ok(Temperature::Reaumur(re)) if re == 80.0));
+    assert_eq!(Temperature::try_from("100X").err(), Some(TempError::UnknownUnit(String::from("X"))));
+}
+
+#[test]
+fn leading_dashes_from_documents_are_minus_signs() {
+    assert_eq!(parse_temperature("\u{2013}40"), Ok(-40.0));
+    assert_eq!(parse_temperature("\u{2014}40"), Ok(-40.0));
+    assert_eq!(parse_temperature(" –3.5°C"), Ok(-3.5));
+    assert!(matches!(parse_temperature("4\u{2013}0"), Err(TempError::ParseFailed(_))));
+}
+
+#[test]
+fn degree_signs_are_ignored() {
+    assert_eq!(parse_temperature("98.6°"), Ok(98.6));
+    assert_eq!(parse_temperature("37 °C"), Ok(37.0));
+    assert_eq!(parse_temperature(" -40°F "), Ok(-40.0));
+    assert_eq!(parse_temperature("°100"), Ok(100.0));
+    assert_eq!(parse_temperature("37°Ré"), Ok(37.0));
+    assert_eq!(parse_temperature("37°Re"), Ok(37.0));
+    assert_eq!(parse_temperature("37°Celsius"), Ok(37.0));
+    assert!(parse_temperature("37°Q").is_err());
+    assert_eq!(parse_temperature_tagged("37°Ré"), Ok((37.0, Unit::Reaumur)));
+    assert_eq!(parse_temperature("100"), Ok(100.0));
+    assert!(parse_temperature("°").is_err());
+    assert!(parse_temperature("37 C").is_err());
+}
@@ -0,0 +1,25 @@
+use learning_rust::{celsius_to_fahrenheit, convert, fahrenheit_to_celsius, Unit};
+use proptest::prelude::*;
+
+// relative to the size of the value, with a floor so values near 0 aren't held to an
+// impossibly tight bound
+fn close(actual: f64, expected: f64) -> bool {
+    (actual - expected).abs() <= 1e-12 * expected.abs().max(1.0)
+}
+
+proptest! {
+    // from absolute zero up to about the surface of the sun, plenty for anything real
+    #[test]
+    fn fahrenheit_round_trips_through_celsius(f in -459.67f64..10_000.0) {
+        let back = celsius_to_fahrenheit(fahrenheit_to_celsius(f));
+        prop_assert!(close(back, f), "{f} came back as {back}");
+    }
+
+    #[test]
+    fn celsius_round_trips_through_kelvin_and_rankine(c in -273.15f64..5_500.0) {
+        let k = convert(c, Unit::Celsius, Unit::Kelvin);
+        let r = convert(k, Unit::Kelvin, Unit::Rankine);
+        let back = convert(r, Unit::Rankine, Unit::Celsius);
+        prop_assert!(close(back, c), "{c} came back as {back}");
+    }
+}
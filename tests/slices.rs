@@ -0,0 +1,20 @@
+use learning_rust::slices::first_word;
+
+#[test]
+fn first_word_is_a_slice_of_the_input() {
+    assert_eq!(first_word("hello world"), "hello");
+    assert_eq!(first_word("hello"), "hello");
+    assert_eq!(first_word("tab\tseparated"), "tab");
+}
+
+#[test]
+fn empty_and_blank_strings_have_no_word() {
+    assert_eq!(first_word(""), "");
+    assert_eq!(first_word("   "), "");
+}
+
+#[test]
+fn leading_whitespace_is_skipped() {
+    assert_eq!(first_word("  lead"), "lead");
+    assert_eq!(first_word("\n  lead the way"), "lead");
+}
@@ -0,0 +1,38 @@
+use learning_rust::stats::{average, indices_above, temp_stats};
+
+#[test]
+fn average_of_a_batch() {
+    assert_eq!(average(&[10.0, 20.0, 30.0, 40.0]), Some(25.0));
+}
+
+#[test]
+fn average_of_one_value() {
+    assert_eq!(average(&[-40.0]), Some(-40.0));
+}
+
+#[test]
+fn average_of_nothing() {
+    assert_eq!(average(&[]), None);
+}
+
+#[test]
+fn stats_of_a_batch() {
+    let stats = temp_stats(&[12.0, -3.0, 30.0, 1.0]).unwrap();
+    assert_eq!(stats.min, -3.0);
+    assert_eq!(stats.max, 30.0);
+    assert_eq!(stats.mean, 10.0);
+    assert_eq!(stats.range, 33.0);
+}
+
+#[test]
+fn stats_of_nothing() {
+    assert_eq!(temp_stats(&[]), None);
+}
+
+#[test]
+fn indices_above_skips_ties() {
+    let readings = [29.9, 30.0, 30.1, 12.0, 35.5, 30.0];
+    assert_eq!(indices_above(&readings, 30.0), [2, 4]);
+    assert_eq!(indices_above(&readings, 40.0), Vec::<usize>::new());
+    assert!(indices_above(&[], 30.0).is_empty());
+}
@@ -0,0 +1,55 @@
+use learning_rust::table::{print_all, print_table, print_table_pretty};
+use learning_rust::{TempError, Unit};
+
+#[test]
+fn celsius_to_fahrenheit_table() {
+    let table = print_table(0.0, 100.0, 25.0, Unit::Celsius, Unit::Fahrenheit).unwrap();
+    let lines: Vec<&str> = table.lines().collect();
+
+    // header, divider, and five rows for 0, 25, 50, 75, 100
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], "Celsius | Fahrenheit");
+    assert_eq!(lines[1], "--------+-----------");
+    assert_eq!(lines[3], "     25 |      77.00");
+    assert_eq!(lines[6], "    100 |     212.00");
+}
+
+#[test]
+fn step_has_to_be_positive() {
+    let table = print_table(0.0, 100.0, 0.0, Unit::Celsius, Unit::Fahrenheit);
+    assert_eq!(table, Err(TempError::InvalidStep(0.0)));
+    assert!(print_table(0.0, 100.0, -5.0, Unit::Celsius, Unit::Fahrenheit).is_err());
+}
+
+#[test]
+fn pretty_table_rounds_to_half_degrees() {
+    let table = print_table_pretty(95.0, 100.0, 1.0, Unit::Fahrenheit, Unit::Celsius).unwrap();
+    let results: Vec<&str> = table.lines().skip(2).map(|line| line.rsplit(' ').next().unwrap()).collect();
+
+    // 35, 35.56, 36.11, 36.67, 37.22, 37.78
+    assert_eq!(results, ["35.0", "35.5", "36.0", "36.5", "37.0", "38.0"]);
+}
+
+#[test]
+fn print_all_lines_up_every_unit() {
+    let table = print_all(0.0, Unit::Celsius);
+    let lines: Vec<&str> = table.lines().collect();
+
+    assert_eq!(lines.len(), 2 + Unit::ALL.len());
+    assert_eq!(lines[0], "      Unit | Temperature");
+    assert_eq!(lines[3], "Fahrenheit |     32.00°F");
+    assert_eq!(lines[4], "    Kelvin |     273.15K");
+}
+
+#[test]
+fn results_just_under_zero_print_without_a_minus() {
+    let all = print_all(31.999, Unit::Fahrenheit);
+    assert!(all.contains("0.00°C") && all.contains("0.00°N") && all.contains("0.00°Ré"), "{all}");
+    assert!(!all.contains("-0"), "{all}");
+
+    let table = print_table(31.999, 31.999, 1.0, Unit::Fahrenheit, Unit::Celsius).unwrap();
+    assert!(table.lines().nth(2).unwrap().ends_with(" 0.00"), "{table}");
+
+    let pretty = print_table_pretty(31.9, 31.9, 1.0, Unit::Fahrenheit, Unit::Celsius).unwrap();
+    assert!(pretty.lines().nth(2).unwrap().ends_with(" 0.0"), "{pretty}");
+}
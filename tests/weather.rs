@@ -0,0 +1,52 @@
+use learning_rust::weather::{dew_point, heat_index, wind_chill};
+use learning_rust::TempError;
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() < tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn dew_point_reference_values() {
+    assert_close(dew_point(25.0, 60.0).unwrap(), 16.69, 0.05);
+    assert_close(dew_point(20.0, 50.0).unwrap(), 9.26, 0.05);
+    // saturated air is already at its dew point
+    assert_close(dew_point(15.0, 100.0).unwrap(), 15.0, 1e-9);
+}
+
+#[test]
+fn dew_point_rejects_bad_humidity() {
+    assert_eq!(dew_point(20.0, 101.0), Err(TempError::HumidityOutOfRange(101.0)));
+    assert_eq!(dew_point(20.0, -1.0), Err(TempError::HumidityOutOfRange(-1.0)));
+}
+
+#[test]
+fn bone_dry_air_has_no_dew_point() {
+    assert_eq!(dew_point(20.0, 0.0), Ok(f64::NEG_INFINITY));
+    assert_eq!(dew_point(-10.0, 0.0), Ok(f64::NEG_INFINITY));
+}
+
+#[test]
+fn heat_index_hot_and_humid() {
+    // the NWS heat index chart lists 90°F at 70% as 106°F
+    assert_close(heat_index(90.0, 70.0), 106.0, 0.5);
+}
+
+#[test]
+fn heat_index_below_80_is_unchanged() {
+    assert_eq!(heat_index(75.0, 90.0), 75.0);
+}
+
+#[test]
+fn wind_chill_in_range() {
+    // the NWS wind chill chart lists 0°F at 15 mph as -19°F
+    assert_close(wind_chill(0.0, 15.0), -19.0, 0.5);
+}
+
+#[test]
+fn wind_chill_out_of_range_is_unchanged() {
+    assert_eq!(wind_chill(60.0, 20.0), 60.0);
+    assert_eq!(wind_chill(20.0, 2.0), 20.0);
+}